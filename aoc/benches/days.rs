@@ -0,0 +1,141 @@
+//! Times the parse step and both parts of every day, on the day's example
+//! (`test.txt`) and on its real input, and prints a summary table at the end.
+//!
+//! Run with `cargo bench -p aoc --bench days`, adding `-- day05` for one day.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use aoc::profile::format_duration;
+use common::solution::{Part, Solution};
+use criterion::{Bencher, Criterion};
+
+/// The total time and iteration count of one benchmark, to report its mean.
+#[derive(Default)]
+struct Mean {
+    total: Duration,
+    iters: u64,
+}
+
+impl Mean {
+    /// The mean time, if the benchmark ran.
+    fn get(&self) -> Option<Duration> {
+        (self.iters > 0).then(|| {
+            let nanos = self.total.as_nanos() / u128::from(self.iters);
+            Duration::from_nanos(nanos as u64)
+        })
+    }
+}
+
+struct Row {
+    day: u32,
+    input: &'static str,
+    parse: Mean,
+    part_a: Mean,
+    part_b: Mean,
+}
+
+/// Like `Bencher::iter`, also adding the measured time to `mean`.
+fn iter<O>(b: &mut Bencher, mean: &mut Mean, mut routine: impl FnMut() -> O) {
+    b.iter_custom(|iters| {
+        let start = Instant::now();
+        for _ in 0..iters {
+            black_box(routine());
+        }
+        let elapsed = start.elapsed();
+        mean.total += elapsed;
+        mean.iters += iters;
+        elapsed
+    });
+}
+
+fn bench<S: Solution>(c: &mut Criterion, rows: &mut Vec<Row>, example: &str, real: &str) {
+    for (name, input) in [("example", example), ("input", real)] {
+        let mut row = Row {
+            day: S::DAY,
+            input: name,
+            parse: Mean::default(),
+            part_a: Mean::default(),
+            part_b: Mean::default(),
+        };
+        let mut group = c.benchmark_group(format!("day{:02}/{name}", S::DAY));
+        if name == "input" {
+            group.sample_size(10);
+        }
+        group.bench_function("parse", |b| {
+            iter(b, &mut row.parse, || S::parse(input).unwrap())
+        });
+        let parsed = S::parse(input).unwrap();
+        for (part, mean) in [(Part::A, &mut row.part_a), (Part::B, &mut row.part_b)] {
+            group.bench_function(format!("part_{part}"), |b| match part {
+                Part::A => iter(b, mean, || S::part_a(&parsed)),
+                Part::B => iter(b, mean, || S::part_b(&parsed)),
+            });
+        }
+        group.finish();
+        rows.push(row);
+    }
+}
+
+fn format(mean: &Mean) -> String {
+    mean.get().map_or("-".to_string(), format_duration)
+}
+
+fn print_summary(rows: &[Row]) {
+    println!();
+    println!(
+        "{:<5} {:<8} {:>10} {:>10} {:>10}",
+        "day", "input", "parse", "part a", "part b"
+    );
+    for row in rows {
+        if row.parse.iters == 0 {
+            // filtered out on the command line
+            continue;
+        }
+        println!(
+            "{:<5} {:<8} {:>10} {:>10} {:>10}",
+            format!("{:02}", row.day),
+            row.input,
+            format(&row.parse),
+            format(&row.part_a),
+            format(&row.part_b)
+        );
+    }
+}
+
+macro_rules! bench {
+    ($c:expr, $rows:expr, $solution:ty, $dir:literal) => {
+        bench::<$solution>(
+            $c,
+            $rows,
+            include_str!(concat!("../../", $dir, "/test.txt")),
+            include_str!(concat!("../../", $dir, "/input.txt")),
+        )
+    };
+}
+
+fn main() {
+    let mut c = Criterion::default().configure_from_args();
+    let rows = &mut Vec::new();
+    bench!(&mut c, rows, day01::Day01, "day01");
+    bench!(&mut c, rows, day02::Day02, "day02");
+    bench!(&mut c, rows, day03::Day03, "day03");
+    bench!(&mut c, rows, day04::Day04, "day04");
+    bench!(&mut c, rows, day05::Day05, "day05");
+    bench!(&mut c, rows, day06::Day06, "day06");
+    bench!(&mut c, rows, day07::Day07, "day07");
+    bench!(&mut c, rows, day08::Day08, "day08");
+    bench!(&mut c, rows, day09::Day09, "day09");
+    bench!(&mut c, rows, day10::Day10, "day10");
+    bench!(&mut c, rows, day11::Day11, "day11");
+    bench!(&mut c, rows, day12::Day12, "day12");
+    bench!(&mut c, rows, day13::Day13, "day13");
+    bench!(&mut c, rows, day14::Day14, "day14");
+    bench!(&mut c, rows, day15::Day15, "day15");
+    bench!(&mut c, rows, day16::Day16, "day16");
+    bench!(&mut c, rows, day17::Day17, "day17");
+    bench!(&mut c, rows, day18::Day18, "day18");
+    bench!(&mut c, rows, day19::Day19, "day19");
+    c.final_summary();
+    print_summary(rows);
+}
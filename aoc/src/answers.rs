@@ -0,0 +1,105 @@
+use std::fmt;
+
+use common::solution::{Answer, Part};
+
+pub const BUNDLED: &str = include_str!("../../answers.toml");
+
+/// The known answers, one `[dayNN]` table per day with an `a` and a `b` key.
+pub struct Answers(toml::Table);
+
+impl Answers {
+    pub fn parse(answers: &str) -> Result<Self, String> {
+        answers
+            .parse()
+            .map(Answers)
+            .map_err(|e| format!("invalid answers file: {e}"))
+    }
+
+    /// The recorded answer, formatted the way [`Answer`] prints it.
+    pub fn get(&self, day: u32, part: Part) -> Option<String> {
+        match self.0.get(&format!("day{day:02}"))?.get(part.to_string())? {
+            toml::Value::String(s) => Some(s.clone()),
+            value => Some(value.to_string()),
+        }
+    }
+}
+
+pub enum Verdict {
+    Pass,
+    Fail { expected: String, found: String },
+    Missing { found: String },
+}
+
+impl Verdict {
+    /// `found` is the answer, or the error the day failed with.
+    pub fn new(expected: Option<String>, found: Result<Answer, String>) -> Self {
+        let found = match found {
+            Ok(answer) => answer.to_string(),
+            Err(e) => format!("error: {e}"),
+        };
+        match expected {
+            Some(expected) if expected == found => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected, found },
+            None => Verdict::Missing { found },
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected, found } => {
+                write!(f, "FAIL, expected {expected}, found {found}")
+            }
+            Verdict::Missing { found } => write!(f, "missing, found {found}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::*;
+
+    fn input() -> &'static str {
+        "[day01]
+a = 142
+b = \"text\"
+
+[day02]
+a = 8"
+    }
+
+    #[test]
+    fn test_get() {
+        let answers = Answers::parse(input()).unwrap();
+        assert_eq!(answers.get(1, Part::A), Some("142".to_string()));
+        assert_eq!(answers.get(1, Part::B), Some("text".to_string()));
+        assert_eq!(answers.get(2, Part::B), None);
+        assert_eq!(answers.get(3, Part::A), None);
+        assert!(Answers::parse("[day01").is_err());
+    }
+
+    #[test]
+    fn test_verdict() {
+        let answers = Answers::parse(input()).unwrap();
+        let verdict = |day, part, found: Result<Answer, String>| {
+            Verdict::new(answers.get(day, part), found).to_string()
+        };
+        assert_eq!(verdict(1, Part::A, Ok(142u32.into())), "pass");
+        assert_eq!(
+            verdict(2, Part::A, Ok(9u32.into())),
+            "FAIL, expected 8, found 9"
+        );
+        assert_eq!(
+            verdict(2, Part::A, Err("bad input".to_string())),
+            "FAIL, expected 8, found error: bad input"
+        );
+        assert_eq!(verdict(2, Part::B, Ok(0i64.into())), "missing, found 0");
+    }
+
+    #[test]
+    fn test_bundled() {
+        assert!(Answers::parse(BUNDLED).is_ok());
+    }
+}
@@ -0,0 +1,74 @@
+use std::hint::black_box;
+
+use common::parse::ParseError;
+use common::solution::{Answer, Part, Solution};
+
+use crate::profile::Phase;
+
+/// Called with each phase of a day and a function running it, see
+/// [`Day::profile`].
+pub type Measure<'a> = dyn FnMut(Phase, &mut dyn FnMut()) + 'a;
+
+pub struct Day {
+    pub number: u32,
+    pub input: &'static str,
+    pub solve: fn(&str, Part) -> Result<Answer, ParseError>,
+    /// Runs the parse step and then the given parts, each through `measure`.
+    pub profile: fn(&str, &[Part], &mut Measure) -> Result<(), ParseError>,
+}
+
+fn profile<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    measure: &mut Measure,
+) -> Result<(), ParseError> {
+    let mut parsed = None;
+    measure(Phase::Parse, &mut || parsed = Some(S::parse(input)));
+    let parsed = parsed.expect("measure runs the parse step")?;
+    for &part in parts {
+        measure(Phase::Part(part), &mut || {
+            black_box(match part {
+                Part::A => S::part_a(&parsed),
+                Part::B => S::part_b(&parsed),
+            });
+        });
+    }
+    Ok(())
+}
+
+pub fn find(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+macro_rules! day {
+    ($solution:ty, $input:expr) => {
+        Day {
+            number: <$solution as Solution>::DAY,
+            input: $input,
+            solve: <$solution as Solution>::solve,
+            profile: profile::<$solution>,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(day01::Day01, include_str!("../../day01/input.txt")),
+    day!(day02::Day02, include_str!("../../day02/input.txt")),
+    day!(day03::Day03, include_str!("../../day03/input.txt")),
+    day!(day04::Day04, include_str!("../../day04/input.txt")),
+    day!(day05::Day05, include_str!("../../day05/input.txt")),
+    day!(day06::Day06, include_str!("../../day06/input.txt")),
+    day!(day07::Day07, include_str!("../../day07/input.txt")),
+    day!(day08::Day08, include_str!("../../day08/input.txt")),
+    day!(day09::Day09, include_str!("../../day09/input.txt")),
+    day!(day10::Day10, include_str!("../../day10/input.txt")),
+    day!(day11::Day11, include_str!("../../day11/input.txt")),
+    day!(day12::Day12, include_str!("../../day12/input.txt")),
+    day!(day13::Day13, include_str!("../../day13/input.txt")),
+    day!(day14::Day14, include_str!("../../day14/input.txt")),
+    day!(day15::Day15, include_str!("../../day15/input.txt")),
+    day!(day16::Day16, include_str!("../../day16/input.txt")),
+    day!(day17::Day17, include_str!("../../day17/input.txt")),
+    day!(day18::Day18, include_str!("../../day18/input.txt")),
+    day!(day19::Day19, include_str!("../../day19/input.txt")),
+];
@@ -0,0 +1,217 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const URL: &str = "https://adventofcode.com";
+const YEAR: u32 = 2023;
+const USER_AGENT: &str = "github.com/yusufshalaby/advent-of-code-2023-rust (aoc fetch)";
+
+/// Environment variable holding the value of the adventofcode.com `session`
+/// cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable naming the cache directory.
+pub const CACHE_VAR: &str = "AOC_CACHE";
+/// Environment variable replacing [`URL`], to fetch from a stand-in server.
+pub const URL_VAR: &str = "AOC_URL";
+
+/// The shortest time between two downloads, across runs.
+const MIN_INTERVAL: Duration = Duration::from_secs(5);
+/// The file in the cache holding the time of the last download, in
+/// milliseconds since the Unix epoch.
+const LAST_REQUEST: &str = ".last-request";
+
+/// Downloads puzzle inputs into a cache directory as `dayNN.txt`, the layout
+/// `$AOC_INPUT` expects. An input is only downloaded if it is not cached yet.
+pub struct Fetcher {
+    url: String,
+    session: Option<String>,
+    cache: PathBuf,
+    min_interval: Duration,
+}
+
+impl Fetcher {
+    pub fn new(url: String, session: Option<String>, cache: PathBuf) -> Self {
+        Fetcher {
+            url,
+            session,
+            cache,
+            min_interval: MIN_INTERVAL,
+        }
+    }
+
+    /// Configures the fetcher from `$AOC_URL`, `$AOC_SESSION` and `$AOC_CACHE`.
+    /// The cache defaults to `$XDG_CACHE_HOME/aoc`, then `$HOME/.cache/aoc`.
+    pub fn from_env() -> Result<Self, String> {
+        let url = env::var(URL_VAR).unwrap_or_else(|_| URL.to_string());
+        let session = env::var(SESSION_VAR).ok();
+        let cache = env::var_os(CACHE_VAR)
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("XDG_CACHE_HOME").map(|dir| PathBuf::from(dir).join("aoc")))
+            .or_else(|| env::var_os("HOME").map(|dir| PathBuf::from(dir).join(".cache/aoc")))
+            .ok_or_else(|| format!("cannot find a cache directory, set {CACHE_VAR}"))?;
+        Ok(Fetcher::new(url, session, cache))
+    }
+
+    pub fn path(&self, day: u32) -> PathBuf {
+        self.cache.join(format!("day{day:02}.txt"))
+    }
+
+    /// Returns the path of the cached input for `day`, downloading it first
+    /// if needed.
+    pub fn fetch(&self, day: u32) -> Result<PathBuf, String> {
+        let path = self.path(day);
+        if path.exists() {
+            return Ok(path);
+        }
+        let session = self
+            .session
+            .as_ref()
+            .ok_or_else(|| format!("day {day} is not cached and {SESSION_VAR} is not set"))?;
+        fs::create_dir_all(&self.cache).map_err(|e| format!("{}: {e}", self.cache.display()))?;
+        self.wait();
+
+        let url = format!("{}/{YEAR}/day/{day}/input", self.url);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={session}"))
+            .set("User-Agent", USER_AGENT)
+            .call();
+        let last_request = self.cache.join(LAST_REQUEST);
+        // rounded up, so that the wait before the next download never falls short
+        fs::write(&last_request, now().as_micros().div_ceil(1000).to_string())
+            .map_err(|e| format!("{}: {e}", last_request.display()))?;
+        let input = match response {
+            Ok(response) => response.into_string().map_err(|e| format!("{url}: {e}"))?,
+            Err(ureq::Error::Status(status, _)) => {
+                return Err(format!("{url}: server responded with status {status}"))
+            }
+            Err(e) => return Err(format!("{url}: {e}")),
+        };
+
+        // written aside and renamed so that an interrupted download is not cached
+        let partial = path.with_extension("part");
+        fs::write(&partial, input)
+            .and_then(|()| fs::rename(&partial, &path))
+            .map_err(|e| format!("{}: {e}", path.display()))?;
+        Ok(path)
+    }
+
+    /// Sleeps until `min_interval` has passed since the last download.
+    fn wait(&self) {
+        let last_request = fs::read_to_string(self.cache.join(LAST_REQUEST))
+            .ok()
+            .and_then(|millis| millis.parse().ok())
+            .map(Duration::from_millis);
+        let Some(last_request) = last_request else {
+            return;
+        };
+        let elapsed = now().saturating_sub(last_request);
+        if let Some(remaining) = self.min_interval.checked_sub(elapsed) {
+            thread::sleep(remaining);
+        }
+    }
+}
+
+fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::process;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    use crate::fetch::*;
+
+    fn input() -> &'static str {
+        include_str!("../../day04/test.txt")
+    }
+
+    /// A stand-in for adventofcode.com serving `input()` as the input of day 4
+    /// to the `secret` session. Returns its URL and a count of the requests.
+    fn serve() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let count = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let head: Vec<String> = BufReader::new(&stream)
+                    .lines()
+                    .map(Result::unwrap)
+                    .take_while(|line| !line.is_empty())
+                    .collect();
+                count.fetch_add(1, Ordering::SeqCst);
+                let path = head[0].split(' ').nth(1).unwrap_or_default();
+                let logged_in = head
+                    .iter()
+                    .any(|line| line.to_lowercase() == "cookie: session=secret");
+                let (status, body) = match path {
+                    _ if !logged_in => ("400 Bad Request", "Please log in"),
+                    "/2023/day/4/input" => ("200 OK", input()),
+                    _ => ("404 Not Found", "404 Not Found"),
+                };
+                write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+        (url, requests)
+    }
+
+    fn stand_in(name: &str, session: Option<&str>) -> (Fetcher, Arc<AtomicUsize>) {
+        let cache = env::temp_dir().join(format!("aoc-fetch-{}-{name}", process::id()));
+        let _ = fs::remove_dir_all(&cache);
+        let (url, requests) = serve();
+        let mut fetcher = Fetcher::new(url, session.map(String::from), cache);
+        fetcher.min_interval = Duration::from_millis(200);
+        (fetcher, requests)
+    }
+
+    #[test]
+    fn test_fetch_caches() {
+        let (fetcher, requests) = stand_in("caches", Some("secret"));
+        let path = fetcher.fetch(4).unwrap();
+        assert_eq!(path, fetcher.path(4));
+        assert_eq!(fs::read_to_string(&path).unwrap(), input());
+        assert_eq!(fetcher.fetch(4), Ok(path));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (fetcher, requests) = stand_in("errors", Some("secret"));
+        let error = fetcher.fetch(30).unwrap_err();
+        assert!(error.ends_with("/2023/day/30/input: server responded with status 404"));
+        assert!(!fetcher.path(30).exists());
+
+        let (wrong_session, _) = stand_in("wrong_session", Some("guess"));
+        assert!(wrong_session.fetch(4).unwrap_err().ends_with("status 400"));
+
+        let (no_session, _) = stand_in("no_session", None);
+        let error = no_session.fetch(4).unwrap_err();
+        assert_eq!(error, "day 4 is not cached and AOC_SESSION is not set");
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_fetch_rate_limit() {
+        let (fetcher, requests) = stand_in("rate_limit", Some("secret"));
+        let start = Instant::now();
+        fetcher.fetch(4).unwrap();
+        fetcher.fetch(5).unwrap_err();
+        assert!(start.elapsed() >= fetcher.min_interval);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+}
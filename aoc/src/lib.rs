@@ -0,0 +1,4 @@
+//! The solutions of every day, shared by the `aoc` runner and the benchmarks.
+
+pub mod days;
+pub mod profile;
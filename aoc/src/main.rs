@@ -0,0 +1,378 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+use std::process;
+
+use aoc::days::{self, Day, DAYS};
+use aoc::profile::{self, Counting, Row};
+use common::input;
+use common::solution::Part;
+
+use crate::answers::{Answers, Verdict};
+use crate::fetch::Fetcher;
+use crate::scaffold::Example;
+
+mod answers;
+mod fetch;
+mod scaffold;
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+const USAGE: &str = "usage: aoc run [DAY] [--part a|b] [--input PATH|-]
+       aoc verify [DAY] [--part a|b] [--answers PATH]
+       aoc report [DAY] [--part a|b] [--input PATH|-] [--json]
+       aoc fetch DAY...
+       aoc new DAY [--example PATH|-] [--answer-a N] [--answer-b N]
+       aoc standings [--part a|b] [--input PATH|-]
+       aoc dot [--from NODE] [--input PATH|-]
+
+Runs the solution for DAY, or for every day if DAY is omitted.
+Without --part both parts are run. --input reads the puzzle input
+from PATH, or from stdin when PATH is '-'. Without --input the
+input is read from $AOC_INPUT/dayNN.txt if AOC_INPUT is set, and
+otherwise the day's bundled input.txt is used.
+
+verify checks the answers against answers.toml, or against the
+answers file at PATH, and reports each part as pass, FAIL or
+missing. It exits with an error if any part fails. answers.toml
+holds the answers for the bundled inputs, so those are used
+unless --answers is given, which uses the inputs run would.
+
+report runs the days like run, and shows the time, the peak heap
+allocation and the number of allocations of the parse step and of
+each part, as a table or, with --json, as JSON.
+
+fetch downloads the input of each DAY from adventofcode.com using
+the session cookie in $AOC_SESSION, and prints where it is cached.
+Inputs are cached as dayNN.txt in $AOC_CACHE, by default
+~/.cache/aoc, and never downloaded twice. Point AOC_INPUT at the
+cache to run the days on them.
+
+new creates the crate dayNN from template/ and adds it to the
+workspace, the runner and the benchmarks. --example saves the
+example input from PATH, or from stdin when PATH is '-', as the
+day's test.txt and its tests' input, which are expected to give
+the answers of --answer-a and --answer-b.
+
+standings prints the hands of day 7 from the strongest down, with
+what the jokers became, each hand's category, rank and winnings,
+under the rules of each part. --input is read as for run.
+
+dot prints the network of day 8 as a Graphviz DOT graph, with
+start nodes in green and end nodes in red. --from only keeps the
+nodes reachable from NODE. --input is read as for run.";
+
+/// Usage errors are reported with the usage text, failures while loading or
+/// solving a day without it.
+#[derive(Debug)]
+enum Error {
+    Usage(String),
+    Failed(String),
+}
+
+#[derive(Debug, Default)]
+struct RunArgs {
+    day: Option<u32>,
+    part: Option<Part>,
+    input: Option<String>,
+    answers: Option<String>,
+    json: bool,
+}
+
+/// Parses the arguments of `run`, `verify` or `report`, which share the
+/// selection of days and parts.
+fn parse_run_args(
+    mut args: impl Iterator<Item = String>,
+    command: &str,
+) -> Result<RunArgs, String> {
+    let verify = command == "verify";
+    let mut run_args = RunArgs::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let part = args.next().ok_or("--part needs a value")?;
+                run_args.part = Some(part.parse()?);
+            }
+            "--input" | "-i" if !verify => {
+                run_args.input = Some(args.next().ok_or("--input needs a value")?);
+            }
+            "--answers" | "-a" if verify => {
+                run_args.answers = Some(args.next().ok_or("--answers needs a value")?);
+            }
+            "--json" if command == "report" => run_args.json = true,
+            _ if run_args.day.is_none() => {
+                let day = arg
+                    .parse::<u32>()
+                    .map_err(|_| format!("invalid day '{arg}'"))?;
+                run_args.day = Some(day);
+            }
+            _ => return Err(format!("unexpected argument '{arg}'")),
+        }
+    }
+    Ok(run_args)
+}
+
+fn select(run_args: &RunArgs) -> Result<(Vec<&'static Day>, Vec<Part>), Error> {
+    if run_args.input.is_some() && run_args.day.is_none() {
+        return Err(Error::Usage("--input needs a DAY".to_string()));
+    }
+    let days = match run_args.day {
+        Some(number) => vec![days::find(number)
+            .ok_or_else(|| Error::Usage(format!("day {number} is not solved")))?],
+        None => DAYS.iter().collect(),
+    };
+    let parts = match run_args.part {
+        Some(part) => vec![part],
+        None => vec![Part::A, Part::B],
+    };
+    Ok((days, parts))
+}
+
+fn run(run_args: RunArgs) -> Result<(), Error> {
+    let (days, parts) = select(&run_args)?;
+
+    // a single answer is printed bare so it can be piped into other tools
+    let labelled = days.len() > 1 || parts.len() > 1;
+    for day in days {
+        let input = input::load(day.number, run_args.input.as_deref(), day.input)
+            .map_err(|e| Error::Failed(e.to_string()))?;
+        for &part in &parts {
+            let answer = (day.solve)(&input, part)
+                .map_err(|e| Error::Failed(format!("day {}: {}", day.number, e)))?;
+            if labelled {
+                println!("day {:02}{}: {}", day.number, part, answer);
+            } else {
+                println!("{answer}");
+            }
+        }
+    }
+    Ok(())
+}
+
+fn verify(run_args: RunArgs) -> Result<(), Error> {
+    let (days, parts) = select(&run_args)?;
+    let answers = match &run_args.answers {
+        Some(path) => {
+            fs::read_to_string(path).map_err(|e| Error::Failed(format!("{path}: {e}")))?
+        }
+        None => answers::BUNDLED.to_string(),
+    };
+    let answers = Answers::parse(&answers).map_err(Error::Failed)?;
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for day in days {
+        // the bundled answers belong to the bundled inputs, whatever $AOC_INPUT says
+        let input = match run_args.answers {
+            Some(_) => input::load(day.number, None, day.input).map_err(|e| e.to_string()),
+            None => Ok(day.input.to_string()),
+        };
+        for &part in &parts {
+            let found = input
+                .as_ref()
+                .map_err(Clone::clone)
+                .and_then(|input| (day.solve)(input, part).map_err(|e| e.to_string()));
+            let verdict = Verdict::new(answers.get(day.number, part), found);
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Fail { .. } => failed += 1,
+                Verdict::Missing { .. } => missing += 1,
+            }
+            println!("day {:02}{}: {}", day.number, part, verdict);
+        }
+    }
+    println!("{passed} passed, {failed} failed, {missing} missing");
+    if failed > 0 {
+        return Err(Error::Failed(format!("{failed} answers do not match")));
+    }
+    Ok(())
+}
+
+fn report(run_args: RunArgs) -> Result<(), Error> {
+    let (days, parts) = select(&run_args)?;
+    let mut rows = Vec::new();
+    for day in days {
+        let input = input::load(day.number, run_args.input.as_deref(), day.input)
+            .map_err(|e| Error::Failed(e.to_string()))?;
+        (day.profile)(&input, &parts, &mut |phase, run| {
+            rows.push(Row {
+                day: day.number,
+                phase,
+                usage: profile::measure(run),
+            });
+        })
+        .map_err(|e| Error::Failed(format!("day {}: {}", day.number, e)))?;
+    }
+    if run_args.json {
+        print!("{}", profile::json(&rows));
+    } else {
+        print!("{}", profile::table(&rows));
+    }
+    Ok(())
+}
+
+fn fetch(args: impl Iterator<Item = String>) -> Result<(), Error> {
+    let days = args
+        .map(|arg| {
+            arg.parse::<u32>()
+                .ok()
+                .filter(|day| (1..=25).contains(day))
+                .ok_or_else(|| Error::Usage(format!("invalid day '{arg}'")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if days.is_empty() {
+        return Err(Error::Usage("fetch needs a DAY".to_string()));
+    }
+    let fetcher = Fetcher::from_env().map_err(Error::Failed)?;
+    for day in days {
+        let path = fetcher.fetch(day).map_err(Error::Failed)?;
+        println!("{}", path.display());
+    }
+    Ok(())
+}
+
+fn new(mut args: impl Iterator<Item = String>) -> Result<(), Error> {
+    let mut day = None;
+    let mut example = Example::default();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| Error::Usage(format!("{name} needs a value")))
+        };
+        let answer = |name: &str, value: String| {
+            value
+                .parse::<i64>()
+                .map(Some)
+                .map_err(|_| Error::Usage(format!("invalid {name} '{value}'")))
+        };
+        match arg.as_str() {
+            "--example" | "-e" => {
+                let path = value("--example")?;
+                let mut input = String::new();
+                let read = match path.as_str() {
+                    "-" => io::stdin().read_to_string(&mut input).map(|_| ()),
+                    path => fs::read_to_string(path).map(|text| input = text),
+                };
+                read.map_err(|e| Error::Failed(format!("{path}: {e}")))?;
+                example.input = input;
+            }
+            "--answer-a" => example.answer_a = answer("answer", value("--answer-a")?)?,
+            "--answer-b" => example.answer_b = answer("answer", value("--answer-b")?)?,
+            _ if day.is_none() => {
+                day = Some(
+                    arg.parse::<u32>()
+                        .ok()
+                        .filter(|day| (1..=25).contains(day))
+                        .ok_or_else(|| Error::Usage(format!("invalid day '{arg}'")))?,
+                );
+            }
+            _ => return Err(Error::Usage(format!("unexpected argument '{arg}'"))),
+        }
+    }
+    let day = day.ok_or_else(|| Error::Usage("new needs a DAY".to_string()))?;
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner is part of the workspace");
+    scaffold::new_day(root, day, &example).map_err(Error::Failed)?;
+    println!("created {}", root.join(format!("day{day:02}")).display());
+    Ok(())
+}
+
+fn standings(mut args: impl Iterator<Item = String>) -> Result<(), Error> {
+    let (mut parts, mut path) = (vec![Part::A, Part::B], None);
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| Error::Usage(format!("{name} needs a value")))
+        };
+        match arg.as_str() {
+            "--part" | "-p" => parts = vec![value("--part")?.parse().map_err(Error::Usage)?],
+            "--input" | "-i" => path = Some(value("--input")?),
+            _ => return Err(Error::Usage(format!("unexpected argument '{arg}'"))),
+        }
+    }
+    let day = days::find(7).expect("day 7 is solved");
+    let input = input::load(day.number, path.as_deref(), day.input)
+        .map_err(|e| Error::Failed(e.to_string()))?;
+    for (i, part) in parts.iter().enumerate() {
+        let rules = match part {
+            Part::A => day07::Rules::standard(),
+            Part::B => day07::Rules::jokers(),
+        };
+        let hands = rules
+            .parse_hands(&input)
+            .map_err(|e| Error::Failed(format!("day 7: {e}")))?;
+        if parts.len() > 1 {
+            println!("{}part {part}", if i > 0 { "\n" } else { "" });
+        }
+        print!("{}", day07::standings_table(&rules.standings(&hands)));
+    }
+    Ok(())
+}
+
+fn dot(mut args: impl Iterator<Item = String>) -> Result<(), Error> {
+    let (mut from, mut path) = (None, None);
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| Error::Usage(format!("{name} needs a value")))
+        };
+        match arg.as_str() {
+            "--from" | "-f" => from = Some(value("--from")?),
+            "--input" | "-i" => path = Some(value("--input")?),
+            _ => return Err(Error::Usage(format!("unexpected argument '{arg}'"))),
+        }
+    }
+    let day = days::find(8).expect("day 8 is solved");
+    let input = input::load(day.number, path.as_deref(), day.input)
+        .map_err(|e| Error::Failed(e.to_string()))?;
+    let network =
+        day08::Network::parse(&input).map_err(|e| Error::Failed(format!("day 8: {e}")))?;
+    let start = match from {
+        Some(name) => Some(
+            network
+                .id(&name)
+                .ok_or_else(|| Error::Failed(format!("day 8 has no node '{name}'")))?,
+        ),
+        None => None,
+    };
+    print!("{}", day08::to_dot(&network, start));
+    Ok(())
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let result = match args.next().as_deref() {
+        Some("run") => parse_run_args(args, "run")
+            .map_err(Error::Usage)
+            .and_then(run),
+        Some("verify") => parse_run_args(args, "verify")
+            .map_err(Error::Usage)
+            .and_then(verify),
+        Some("report") => parse_run_args(args, "report")
+            .map_err(Error::Usage)
+            .and_then(report),
+        Some("fetch") => fetch(args),
+        Some("new") => new(args),
+        Some("standings") => standings(args),
+        Some("dot") => dot(args),
+        Some("-h") | Some("--help") => {
+            println!("{USAGE}");
+            return;
+        }
+        Some(command) => Err(Error::Usage(format!("unknown command '{command}'"))),
+        None => Err(Error::Usage("missing command".to_string())),
+    };
+    match result {
+        Ok(()) => {}
+        Err(Error::Usage(err)) => {
+            eprintln!("error: {err}\n\n{USAGE}");
+            process::exit(2);
+        }
+        Err(Error::Failed(err)) => {
+            eprintln!("error: {err}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! Time and heap usage of the parse step and the parts of each day.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use common::solution::Part;
+
+thread_local! {
+    static CURRENT: Cell<usize> = const { Cell::new(0) };
+    static PEAK: Cell<usize> = const { Cell::new(0) };
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// The system allocator, counting the allocations and the heap in use of
+/// each thread for [`measure`]. It has to be installed as the
+/// `#[global_allocator]` for the counts to be anything but zero.
+pub struct Counting;
+
+fn allocated(size: usize) {
+    // the thread locals may be gone while a thread shuts down
+    let _ = CURRENT.try_with(|current| {
+        current.set(current.get() + size);
+        PEAK.with(|peak| peak.set(peak.get().max(current.get())));
+        ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+    });
+}
+
+fn freed(size: usize) {
+    // memory allocated by another thread may be freed here
+    let _ = CURRENT.try_with(|current| current.set(current.get().saturating_sub(size)));
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        freed(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part(Part),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(part) => write!(f, "part {part}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Usage {
+    pub time: Duration,
+    /// The most heap in use at once, beyond what was in use before.
+    pub peak_bytes: usize,
+    pub allocations: usize,
+}
+
+/// Runs `f` on this thread, measuring its time and heap usage.
+pub fn measure(f: &mut dyn FnMut()) -> Usage {
+    let start = CURRENT.with(Cell::get);
+    PEAK.with(|peak| peak.set(start));
+    let allocations = ALLOCATIONS.with(Cell::get);
+    let now = Instant::now();
+    f();
+    let time = now.elapsed();
+    Usage {
+        time,
+        peak_bytes: PEAK.with(Cell::get).saturating_sub(start),
+        allocations: ALLOCATIONS.with(Cell::get) - allocations,
+    }
+}
+
+pub struct Row {
+    pub day: u32,
+    pub phase: Phase,
+    pub usage: Usage,
+}
+
+/// A duration with three significant digits or so, in the largest unit that
+/// keeps it above one.
+pub fn format_duration(duration: Duration) -> String {
+    match duration.as_nanos() as f64 {
+        n if n < 1e3 => format!("{n:.0} ns"),
+        n if n < 1e6 => format!("{:.1} µs", n / 1e3),
+        n if n < 1e9 => format!("{:.1} ms", n / 1e6),
+        n => format!("{:.2} s", n / 1e9),
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    match bytes as f64 {
+        n if n < 1024.0 => format!("{n:.0} B"),
+        n if n < 1024.0 * 1024.0 => format!("{:.1} KiB", n / 1024.0),
+        n => format!("{:.1} MiB", n / (1024.0 * 1024.0)),
+    }
+}
+
+pub fn table(rows: &[Row]) -> String {
+    let mut table = format!(
+        "{:<5} {:<8} {:>10} {:>12} {:>12}\n",
+        "day", "phase", "time", "peak heap", "allocations"
+    );
+    for row in rows {
+        table += &format!(
+            "{:<5} {:<8} {:>10} {:>12} {:>12}\n",
+            format!("{:02}", row.day),
+            row.phase.to_string(),
+            format_duration(row.usage.time),
+            format_bytes(row.usage.peak_bytes),
+            row.usage.allocations
+        );
+    }
+    table
+}
+
+/// The rows as a JSON array with one object per row.
+pub fn json(rows: &[Row]) -> String {
+    let objects: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            format!(
+                "  {{\"day\": {}, \"phase\": \"{}\", \"nanos\": {}, \"peak_bytes\": {}, \"allocations\": {}}}",
+                row.day,
+                row.phase,
+                row.usage.time.as_nanos(),
+                row.usage.peak_bytes,
+                row.usage.allocations
+            )
+        })
+        .collect();
+    format!("[\n{}\n]\n", objects.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::profile::*;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    fn rows() -> Vec<Row> {
+        vec![
+            Row {
+                day: 4,
+                phase: Phase::Parse,
+                usage: Usage {
+                    time: Duration::from_micros(1500),
+                    peak_bytes: 2048,
+                    allocations: 3,
+                },
+            },
+            Row {
+                day: 4,
+                phase: Phase::Part(Part::B),
+                usage: Usage {
+                    time: Duration::from_nanos(20),
+                    peak_bytes: 0,
+                    allocations: 0,
+                },
+            },
+        ]
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut kept = Vec::new();
+        let usage = measure(&mut || {
+            let scratch = vec![0u8; 1 << 20];
+            kept = vec![scratch[0]; 10];
+        });
+        assert_eq!(usage.allocations, 2);
+        assert_eq!(usage.peak_bytes, (1 << 20) + 10);
+        assert_eq!(kept.len(), 10);
+
+        let usage = measure(&mut || kept.push(1));
+        assert_eq!(usage.allocations, 1);
+        assert_eq!(usage.peak_bytes, 20 - 10);
+    }
+
+    #[test]
+    fn test_table() {
+        assert_eq!(
+            table(&rows()),
+            "day   phase          time    peak heap  allocations
+04    parse        1.5 ms      2.0 KiB            3
+04    part b        20 ns          0 B            0
+"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            json(&rows()),
+            r#"[
+  {"day": 4, "phase": "parse", "nanos": 1500000, "peak_bytes": 2048, "allocations": 3},
+  {"day": 4, "phase": "part b", "nanos": 20, "peak_bytes": 0, "allocations": 0}
+]
+"#
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use std::fs;
+use std::path::Path;
+
+/// What to fill the test module of a new day with.
+#[derive(Debug, Default)]
+pub struct Example {
+    pub input: String,
+    pub answer_a: Option<i64>,
+    pub answer_b: Option<i64>,
+}
+
+/// Replaces the placeholders of a template file with the names of `day`, and
+/// fills its test module with `example`.
+fn render(template: &str, day: u32, example: &Example) -> String {
+    let answer = |answer: Option<i64>| answer.unwrap_or_default().to_string();
+    template
+        .replace("dayxx", &format!("day{day:02}"))
+        .replace("DayXX", &format!("Day{day:02}"))
+        .replace(
+            "(dayxa(input), Ok(0))",
+            &format!("(dayxa(input), Ok({}))", answer(example.answer_a)),
+        )
+        .replace(
+            "(dayxb(input), Ok(0))",
+            &format!("(dayxb(input), Ok({}))", answer(example.answer_b)),
+        )
+        .replace("dayxa", &format!("day{day}a"))
+        .replace("dayxb", &format!("day{day}b"))
+        .replace("test_xa", &format!("test_{day}a"))
+        .replace("test_xb", &format!("test_{day}b"))
+        .replace("const DAY: u32 = 0;", &format!("const DAY: u32 = {day};"))
+        .replace(
+            "fn input() -> &'static str {\n        \"\"\n",
+            &format!(
+                "fn input() -> &'static str {{\n        {}\n",
+                literal(example.input.trim_end())
+            ),
+        )
+}
+
+/// A string literal of `s`, raw if it holds quotes or backslashes.
+fn literal(s: &str) -> String {
+    if !s.contains(['"', '\\']) {
+        return format!("\"{s}\"");
+    }
+    let mut hashes = String::new();
+    while s.contains(&format!("\"{hashes}")) {
+        hashes.push('#');
+    }
+    format!("r{hashes}\"{s}\"{hashes}")
+}
+
+/// Inserts `line` among the lines of `text` that start with `marker`, keeping
+/// them sorted.
+fn register(text: &str, marker: &str, line: &str) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    if lines.contains(&line) {
+        return Err(format!("'{}' is already registered", line.trim()));
+    }
+    let marked: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with(marker))
+        .collect();
+    let Some(&first) = marked.first() else {
+        return Err(format!("no line starts with '{}'", marker.trim()));
+    };
+    let at = marked
+        .iter()
+        .rev()
+        .find(|&&i| lines[i] < line)
+        .map_or(first, |&i| i + 1);
+    lines.insert(at, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn edit(path: &Path, edit: impl FnOnce(&str) -> Result<String, String>) -> Result<(), String> {
+    let error = |e: String| format!("{}: {e}", path.display());
+    let text = fs::read_to_string(path).map_err(|e| error(e.to_string()))?;
+    fs::write(path, edit(&text).map_err(error)?).map_err(|e| error(e.to_string()))
+}
+
+fn copy_template(from: &Path, to: &Path, day: u32, example: &Example) -> Result<(), String> {
+    let error = |path: &Path, e: std::io::Error| format!("{}: {e}", path.display());
+    fs::create_dir(to).map_err(|e| error(to, e))?;
+    for entry in fs::read_dir(from).map_err(|e| error(from, e))? {
+        let entry = entry.map_err(|e| error(from, e))?;
+        let (source, target) = (entry.path(), to.join(entry.file_name()));
+        if ["target", "Cargo.lock"].contains(&&*entry.file_name().to_string_lossy()) {
+            // left behind by building the template on its own
+            continue;
+        }
+        if source.is_dir() {
+            copy_template(&source, &target, day, example)?;
+        } else {
+            let template = fs::read_to_string(&source).map_err(|e| error(&source, e))?;
+            let text = render(&template, day, example);
+            fs::write(&target, text).map_err(|e| error(&target, e))?;
+        }
+    }
+    Ok(())
+}
+
+/// Creates the crate `dayNN` in the workspace at `root` from `root/template`,
+/// with `example` as its `test.txt`, and registers it in the workspace, the
+/// runner and the benchmarks.
+pub fn new_day(root: &Path, day: u32, example: &Example) -> Result<(), String> {
+    let name = format!("day{day:02}");
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    copy_template(&root.join("template"), &dir, day, example)?;
+    let test = dir.join("test.txt");
+    fs::write(&test, &example.input).map_err(|e| format!("{}: {e}", test.display()))?;
+
+    edit(&root.join("Cargo.toml"), |text| {
+        register(text, "    \"day", &format!("    \"{name}\","))
+    })?;
+    edit(&root.join("aoc/Cargo.toml"), |text| {
+        register(text, "day", &format!("{name} = {{ path = \"../{name}\" }}"))
+    })?;
+    edit(&root.join("aoc/src/days.rs"), |text| {
+        let line =
+            format!("    day!({name}::Day{day:02}, include_str!(\"../../{name}/input.txt\")),");
+        register(text, "    day!(", &line)
+    })?;
+    edit(&root.join("aoc/benches/days.rs"), |text| {
+        let line = format!("    bench!(&mut c, rows, {name}::Day{day:02}, \"{name}\");");
+        register(text, "    bench!(", &line)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::process;
+
+    use crate::scaffold::*;
+
+    fn example() -> Example {
+        Example {
+            input: "1 2\n3 4\n".to_string(),
+            answer_a: Some(10),
+            answer_b: None,
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let template = include_str!("../../template/src/lib.rs");
+        let lib = render(template, 7, &example());
+        assert!(lib.contains("pub fn day7a(input: &str)"));
+        assert!(lib.contains("pub struct Day07;"));
+        assert!(lib.contains("const DAY: u32 = 7;"));
+        assert!(lib.contains("fn input() -> &'static str {\n        \"1 2\n3 4\"\n    }"));
+        assert!(lib.contains("fn test_7a() {"));
+        assert!(lib.contains("assert_eq!(day7a(input), Ok(10));"));
+        assert!(lib.contains("assert_eq!(day7b(input), Ok(0));"));
+        assert!(!lib.contains("xx") && !lib.contains("XX"));
+        // answers are parsed as i64, so the parts must return one
+        assert!(lib.contains("pub fn day7a(input: &str) -> Result<i64, ParseError>"));
+        let large = Example {
+            answer_b: Some(i64::MAX),
+            ..example()
+        };
+        let lib = render(template, 7, &large);
+        assert!(lib.contains(&format!("assert_eq!(day7b(input), Ok({}));", i64::MAX)));
+
+        let main = render(include_str!("../../template/src/main.rs"), 7, &example());
+        assert!(main.contains("run::<day07::Day07>"));
+    }
+
+    #[test]
+    fn test_literal() {
+        assert_eq!(literal("a\nb"), "\"a\nb\"");
+        assert_eq!(literal(r"\.|"), r#"r"\.|""#);
+        assert_eq!(literal(r#"say "hi""#), r##"r#"say "hi""#"##);
+    }
+
+    #[test]
+    fn test_register() {
+        let text = "[\n    \"common\",\n    \"day04\",\n    \"day09\",\n]\n";
+        assert_eq!(
+            register(text, "    \"day", "    \"day05\",").unwrap(),
+            "[\n    \"common\",\n    \"day04\",\n    \"day05\",\n    \"day09\",\n]\n"
+        );
+        assert_eq!(
+            register(text, "    \"day", "    \"day20\",").unwrap(),
+            "[\n    \"common\",\n    \"day04\",\n    \"day09\",\n    \"day20\",\n]\n"
+        );
+        assert_eq!(
+            register(text, "    \"day", "    \"day01\",").unwrap(),
+            "[\n    \"common\",\n    \"day01\",\n    \"day04\",\n    \"day09\",\n]\n"
+        );
+        assert!(register(text, "    \"day", "    \"day09\",").is_err());
+    }
+
+    fn copy_dir(from: &Path, to: &Path) {
+        fs::create_dir_all(to).unwrap();
+        for entry in fs::read_dir(from).unwrap() {
+            let path = entry.unwrap().path();
+            let target = to.join(path.file_name().unwrap());
+            if path.is_dir() {
+                copy_dir(&path, &target);
+            } else {
+                fs::copy(&path, &target).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_new_day() {
+        let repo = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for file in [
+            "Cargo.toml",
+            "aoc/Cargo.toml",
+            "aoc/src/days.rs",
+            "aoc/benches/days.rs",
+        ] {
+            fs::create_dir_all(root.join(file).parent().unwrap()).unwrap();
+            fs::copy(repo.join(file), root.join(file)).unwrap();
+        }
+        copy_dir(&repo.join("template/src"), &root.join("template/src"));
+        for file in ["template/Cargo.toml", "template/input.txt"] {
+            fs::copy(repo.join(file), root.join(file)).unwrap();
+        }
+
+        new_day(&root, 25, &example()).unwrap();
+        let read = |file: &str| fs::read_to_string(root.join(file)).unwrap();
+        assert!(read("day25/Cargo.toml").contains("name = \"day25\""));
+        assert!(read("day25/src/lib.rs").contains("pub fn day25a"));
+        assert_eq!(read("day25/test.txt"), example().input);
+        assert!(read("Cargo.toml").contains("    \"day25\",\n]"));
+        assert!(read("aoc/Cargo.toml").contains("day25 = { path = \"../day25\" }"));
+        assert!(read("aoc/src/days.rs").contains("day!(day25::Day25"));
+        assert!(
+            read("aoc/benches/days.rs").contains("bench!(&mut c, rows, day25::Day25, \"day25\");")
+        );
+
+        assert!(new_day(&root, 25, &example()).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
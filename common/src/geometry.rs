@@ -0,0 +1,198 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::grid::Pos;
+
+/// One of the four directions along the rows and columns of a grid, where
+/// north points to the first row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction4 {
+    /// All directions, clockwise from north.
+    pub const ALL: [Direction4; 4] = [
+        Direction4::North,
+        Direction4::East,
+        Direction4::South,
+        Direction4::West,
+    ];
+
+    pub fn turn_left(self) -> Self {
+        Direction4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Direction4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Direction4::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction4::East | Direction4::West)
+    }
+
+    /// The change in `(row, column)` of one step.
+    pub fn offset(self) -> (isize, isize) {
+        Direction8::from(self).offset()
+    }
+}
+
+/// One of the four directions of [`Direction4`] or the diagonals between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// All directions, clockwise from north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// Turns by 45 degrees.
+    pub fn turn_left(self) -> Self {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Turns by 45 degrees.
+    pub fn turn_right(self) -> Self {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    /// The change in `(row, column)` of one step.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction8::North => (-1, 0),
+            Direction8::NorthEast => (-1, 1),
+            Direction8::East => (0, 1),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (1, 0),
+            Direction8::SouthWest => (1, -1),
+            Direction8::West => (0, -1),
+            Direction8::NorthWest => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+/// A position that, unlike a grid [`Pos`], may be negative or unbounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Point<T> {
+    pub row: T,
+    pub col: T,
+}
+
+impl<T> Point<T> {
+    pub fn new(row: T, col: T) -> Self {
+        Point { row, col }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Point::new(self.row * factor, self.col * factor)
+    }
+}
+
+impl Point<i64> {
+    /// Moves `distance` steps towards `direction`.
+    pub fn step(self, direction: impl Into<Direction8>, distance: i64) -> Self {
+        let (row, col) = direction.into().offset();
+        self + Point::new(row as i64, col as i64) * distance
+    }
+
+    pub fn manhattan(self, other: Self) -> i64 {
+        (self.row - other.row).abs() + (self.col - other.col).abs()
+    }
+
+    /// The grid position of this point, or `None` if it is outside a grid
+    /// of `width` by `height`.
+    pub fn to_pos(self, width: usize, height: usize) -> Option<Pos> {
+        let row = usize::try_from(self.row).ok().filter(|&row| row < height)?;
+        let col = usize::try_from(self.col).ok().filter(|&col| col < width)?;
+        Some((row, col))
+    }
+}
+
+impl From<Pos> for Point<i64> {
+    fn from((row, col): Pos) -> Self {
+        Point::new(row as i64, col as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::*;
+
+    #[test]
+    fn test_turns() {
+        for direction in Direction4::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+        }
+        assert_eq!(Direction4::North.turn_left(), Direction4::West);
+        assert_eq!(Direction8::North.turn_left(), Direction8::NorthWest);
+        assert_eq!(Direction8::SouthWest.opposite(), Direction8::NorthEast);
+        assert_eq!(Direction8::from(Direction4::South), Direction8::South);
+        assert_eq!(Direction4::West.offset(), (0, -1));
+    }
+
+    #[test]
+    fn test_point() {
+        let point = Point::new(2, 3).step(Direction4::North, 5);
+        assert_eq!(point, Point::new(-3, 3));
+        assert_eq!(point.step(Direction8::SouthEast, 2), Point::new(-1, 5));
+        assert_eq!(point.manhattan(Point::default()), 6);
+        assert_eq!(point.to_pos(10, 10), None);
+        assert_eq!(Point::new(1, 4).to_pos(5, 2), Some((1, 4)));
+        assert_eq!(Point::new(1, 5).to_pos(5, 2), None);
+        assert_eq!(Point::from((7, 8)), Point::new(7, 8));
+    }
+}
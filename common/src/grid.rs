@@ -0,0 +1,370 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::geometry::{Direction4, Direction8};
+use crate::parse::{Context, ParseError};
+
+/// A `(row, column)` position in a grid.
+pub type Pos = (usize, usize);
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are not exactly `width * height` cells.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "grid is not {width}x{height}");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses each character of `input` into a cell, see [`Context::grid`].
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        Context::new(input).grid(input, expected, cell)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.0 * self.width + pos.1])
+    }
+
+    /// Moves from `pos` by `(rows, cols)`, or `None` if that leaves the grid.
+    pub fn step(&self, (row, col): Pos, (rows, cols): (isize, isize)) -> Option<Pos> {
+        let pos = (row.checked_add_signed(rows)?, col.checked_add_signed(cols)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Moves one step from `pos` towards `direction`, or `None` if that
+    /// leaves the grid.
+    pub fn neighbour(&self, pos: Pos, direction: impl Into<Direction8>) -> Option<Pos> {
+        self.step(pos, direction.into().offset())
+    }
+
+    /// The up to four positions sharing an edge with `pos`.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction4::ALL
+            .into_iter()
+            .filter_map(move |direction| self.neighbour(pos, direction))
+    }
+
+    /// The up to eight positions sharing an edge or a corner with `pos`.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.neighbour(pos, direction))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on zero, an empty grid has no rows either way
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {col} out of range");
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// An unchanged view, to be transposed, rotated or flipped.
+    pub fn view(&self) -> View<'_, T> {
+        View {
+            grid: self,
+            transpose: false,
+            flip_rows: false,
+            flip_cols: false,
+        }
+    }
+
+    pub fn transpose(&self) -> View<'_, T> {
+        self.view().transpose()
+    }
+
+    pub fn rotate_clockwise(&self) -> View<'_, T> {
+        self.view().rotate_clockwise()
+    }
+
+    pub fn rotate_counterclockwise(&self) -> View<'_, T> {
+        self.view().rotate_counterclockwise()
+    }
+
+    pub fn flip_vertical(&self) -> View<'_, T> {
+        self.view().flip_vertical()
+    }
+
+    pub fn flip_horizontal(&self) -> View<'_, T> {
+        self.view().flip_horizontal()
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+/// A grid seen transposed, rotated or flipped, without copying its cells.
+///
+/// A view position is flipped first and then transposed to find the cell of
+/// the underlying grid, which covers all eight rotations and reflections.
+#[derive(Debug)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    transpose: bool,
+    flip_rows: bool,
+    flip_cols: bool,
+}
+
+// derived impls would require `T: Clone`, a view only copies the reference
+impl<T> Clone for View<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for View<'_, T> {}
+
+impl<'a, T> View<'a, T> {
+    pub fn width(&self) -> usize {
+        if self.transpose {
+            self.grid.height
+        } else {
+            self.grid.width
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        if self.transpose {
+            self.grid.width
+        } else {
+            self.grid.height
+        }
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&'a T> {
+        if pos.0 >= self.height() || pos.1 >= self.width() {
+            return None;
+        }
+        Some(&self.grid[self.locate(pos)])
+    }
+
+    /// The position in the underlying grid of a position in the view.
+    fn locate(&self, (row, col): Pos) -> Pos {
+        let row = if self.flip_rows {
+            self.height() - 1 - row
+        } else {
+            row
+        };
+        let col = if self.flip_cols {
+            self.width() - 1 - col
+        } else {
+            col
+        };
+        if self.transpose {
+            (col, row)
+        } else {
+            (row, col)
+        }
+    }
+
+    pub fn transpose(self) -> Self {
+        View {
+            transpose: !self.transpose,
+            flip_rows: self.flip_cols,
+            flip_cols: self.flip_rows,
+            ..self
+        }
+    }
+
+    pub fn flip_vertical(self) -> Self {
+        View {
+            flip_rows: !self.flip_rows,
+            ..self
+        }
+    }
+
+    pub fn flip_horizontal(self) -> Self {
+        View {
+            flip_cols: !self.flip_cols,
+            ..self
+        }
+    }
+
+    pub fn rotate_clockwise(self) -> Self {
+        self.transpose().flip_horizontal()
+    }
+
+    pub fn rotate_counterclockwise(self) -> Self {
+        self.transpose().flip_vertical()
+    }
+
+    pub fn row(self, row: usize) -> impl Iterator<Item = &'a T> {
+        (0..self.width()).map(move |col| &self.grid[self.locate((row, col))])
+    }
+
+    pub fn rows(self) -> impl Iterator<Item = impl Iterator<Item = &'a T>> {
+        (0..self.height()).map(move |row| self.row(row))
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = self.rows().flatten().cloned().collect();
+        Grid::new(self.width(), self.height(), cells)
+    }
+}
+
+impl<T> Index<Pos> for View<'_, T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::*;
+
+    fn input() -> &'static str {
+        "abc
+def"
+    }
+
+    fn grid() -> Grid<char> {
+        Grid::parse(input(), "a letter", Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+
+        let error = Grid::parse("ab\nc", "a letter", Some).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "a row of 2 cells");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        let corner: Vec<Pos> = grid.neighbours4((0, 0)).collect();
+        assert_eq!(corner, vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours8((0, 1)).count(), 5);
+        assert_eq!(grid.step((1, 2), (0, 1)), None);
+        assert_eq!(grid.step((1, 2), (-1, -2)), Some((0, 0)));
+        assert_eq!(grid.neighbour((1, 2), Direction8::NorthWest), Some((0, 1)));
+        assert_eq!(grid.neighbour((0, 2), Direction4::North), None);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        let columns: Vec<String> = grid.columns().map(|col| col.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+        assert!(std::panic::catch_unwind(|| grid.column(3).count()).is_err());
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = grid();
+        let show = |view: View<char>| view.to_grid().to_string();
+        assert_eq!(show(grid.view()), "abc\ndef\n");
+        assert_eq!(show(grid.transpose()), "ad\nbe\ncf\n");
+        assert_eq!(show(grid.rotate_clockwise()), "da\neb\nfc\n");
+        assert_eq!(show(grid.rotate_counterclockwise()), "cf\nbe\nad\n");
+        assert_eq!(show(grid.flip_vertical()), "def\nabc\n");
+        assert_eq!(show(grid.flip_horizontal()), "cba\nfed\n");
+        let four_turns = (0..4).fold(grid.view(), |view, _| view.rotate_clockwise());
+        assert_eq!(show(four_turns), "abc\ndef\n");
+        assert_eq!(
+            show(grid.rotate_clockwise().flip_vertical()),
+            "fc\neb\nda\n"
+        );
+    }
+}
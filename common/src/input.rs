@@ -0,0 +1,82 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process;
+
+/// Environment variable naming a directory that holds `dayNN.txt` inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT";
+
+#[derive(Debug, PartialEq)]
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+    Dir(PathBuf),
+    Bundled,
+}
+
+impl Source {
+    /// Picks where the input for `day` comes from: an explicit path (`-` for
+    /// stdin), then `$AOC_INPUT/dayNN.txt`, then the input bundled at compile time.
+    pub fn resolve(day: u32, arg: Option<&str>) -> Source {
+        match arg {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(PathBuf::from(path)),
+            None => match env::var_os(INPUT_DIR_VAR) {
+                Some(dir) => Source::Dir(PathBuf::from(dir).join(format!("day{day:02}.txt"))),
+                None => Source::Bundled,
+            },
+        }
+    }
+
+    pub fn read(&self, bundled: &str) -> io::Result<String> {
+        match self {
+            Source::Path(path) | Source::Dir(path) => fs::read_to_string(path)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e))),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Source::Bundled => Ok(bundled.to_string()),
+        }
+    }
+}
+
+pub fn load(day: u32, arg: Option<&str>, bundled: &str) -> io::Result<String> {
+    Source::resolve(day, arg).read(bundled)
+}
+
+/// Loads the input for a day binary from its first command line argument,
+/// exiting with a message if it cannot be read.
+pub fn from_args(day: u32, bundled: &str) -> String {
+    let arg = env::args().nth(1);
+    load(day, arg.as_deref(), bundled).unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        process::exit(1);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::*;
+
+    #[test]
+    fn test_resolve_arg() {
+        assert_eq!(Source::resolve(5, Some("-")), Source::Stdin);
+        assert_eq!(
+            Source::resolve(5, Some("other.txt")),
+            Source::Path(PathBuf::from("other.txt"))
+        );
+    }
+
+    #[test]
+    fn test_read() {
+        let path = env::temp_dir().join("aoc-common-test-read.txt");
+        fs::write(&path, "1 2 3").unwrap();
+        assert_eq!(Source::Path(path.clone()).read("bundled").unwrap(), "1 2 3");
+        assert_eq!(Source::Bundled.read("bundled").unwrap(), "bundled");
+        fs::remove_file(&path).unwrap();
+        assert!(Source::Path(path).read("bundled").is_err());
+    }
+}
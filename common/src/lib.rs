@@ -0,0 +1,6 @@
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod matcher;
+pub mod parse;
+pub mod solution;
@@ -0,0 +1,185 @@
+use std::collections::{HashMap, VecDeque};
+use std::iter;
+
+/// Finds every occurrence of a set of words in a text, including words that
+/// overlap such as "eight" and "two" in "eightwo", with an Aho-Corasick
+/// automaton. Each word carries a value that is reported with its matches.
+#[derive(Debug, Clone)]
+pub struct Matcher<V> {
+    nodes: Vec<Node>,
+    /// Each word's length in bytes and its value.
+    words: Vec<(usize, V)>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    children: HashMap<u8, usize>,
+    /// The node of the longest proper suffix of this node's prefix that is in
+    /// the trie.
+    fail: usize,
+    /// The word that ends at this node.
+    word: Option<usize>,
+    /// The nearest node on the chain of `fail` links that ends a word.
+    output: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<'a, V> {
+    /// The byte offset of the first byte of the match.
+    pub start: usize,
+    /// The byte offset just past the match.
+    pub end: usize,
+    pub value: &'a V,
+}
+
+impl<V> Matcher<V> {
+    /// Builds the automaton for `vocabulary`. A word listed twice keeps its
+    /// first value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a word is empty.
+    pub fn new<W: AsRef<str>>(vocabulary: impl IntoIterator<Item = (W, V)>) -> Self {
+        let mut nodes = vec![Node::default()];
+        let mut words = Vec::new();
+        for (word, value) in vocabulary {
+            let word = word.as_ref();
+            assert!(!word.is_empty(), "cannot match an empty word");
+            let mut node = 0;
+            for &byte in word.as_bytes() {
+                node = match nodes[node].children.get(&byte) {
+                    Some(&child) => child,
+                    None => {
+                        nodes.push(Node::default());
+                        let child = nodes.len() - 1;
+                        nodes[node].children.insert(byte, child);
+                        child
+                    }
+                };
+            }
+            if nodes[node].word.is_none() {
+                nodes[node].word = Some(words.len());
+                words.push((word.len(), value));
+            }
+        }
+
+        // breadth first, so that the fail link of a node's parent is known
+        let mut queue: VecDeque<usize> = nodes[0].children.values().copied().collect();
+        while let Some(node) = queue.pop_front() {
+            let children: Vec<(u8, usize)> =
+                nodes[node].children.iter().map(|(&b, &c)| (b, c)).collect();
+            for (byte, child) in children {
+                let mut fail = nodes[node].fail;
+                let fail = loop {
+                    match nodes[fail].children.get(&byte) {
+                        Some(&next) => break next,
+                        None if fail == 0 => break 0,
+                        None => fail = nodes[fail].fail,
+                    }
+                };
+                nodes[child].fail = fail;
+                nodes[child].output = if nodes[fail].word.is_some() {
+                    Some(fail)
+                } else {
+                    nodes[fail].output
+                };
+                queue.push_back(child);
+            }
+        }
+        Matcher { nodes, words }
+    }
+
+    fn step(&self, mut node: usize, byte: u8) -> usize {
+        loop {
+            match self.nodes[node].children.get(&byte) {
+                Some(&next) => return next,
+                None if node == 0 => return 0,
+                None => node = self.nodes[node].fail,
+            }
+        }
+    }
+
+    /// Every match in `text`, ordered by where they end and then from the
+    /// longest to the shortest.
+    pub fn find_iter<'a>(&'a self, text: &'a str) -> impl Iterator<Item = Match<'a, V>> + 'a {
+        let mut node = 0;
+        text.bytes().enumerate().flat_map(move |(i, byte)| {
+            node = self.step(node, byte);
+            let first = self.nodes[node]
+                .word
+                .map(|_| node)
+                .or(self.nodes[node].output);
+            iter::successors(first, |&n| self.nodes[n].output).map(move |n| {
+                let (len, value) = &self.words[self.nodes[n].word.unwrap()];
+                Match {
+                    start: i + 1 - len,
+                    end: i + 1,
+                    value,
+                }
+            })
+        })
+    }
+
+    /// The match that starts first, the longest one if several do.
+    pub fn first<'a>(&'a self, text: &'a str) -> Option<Match<'a, V>> {
+        self.find_iter(text)
+            .min_by_key(|m| (m.start, usize::MAX - m.end))
+    }
+
+    /// The match that starts last, the longest one if several do.
+    pub fn last<'a>(&'a self, text: &'a str) -> Option<Match<'a, V>> {
+        self.find_iter(text).max_by_key(|m| (m.start, m.end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matcher::*;
+
+    fn words() -> Matcher<u32> {
+        Matcher::new([
+            ("one", 1),
+            ("two", 2),
+            ("eight", 8),
+            ("eightwo", 82),
+            ("he", 0),
+        ])
+    }
+
+    #[test]
+    fn test_find_iter() {
+        let matcher = words();
+        let matches: Vec<(usize, usize, u32)> = matcher
+            .find_iter("xeightwone")
+            .map(|m| (m.start, m.end, *m.value))
+            .collect();
+        assert_eq!(matches, vec![(1, 6, 8), (1, 8, 82), (5, 8, 2), (7, 10, 1)]);
+        assert_eq!(matcher.find_iter("abc").count(), 0);
+    }
+
+    #[test]
+    fn test_suffix_outputs() {
+        let matcher = Matcher::new([("she", 1), ("he", 2), ("hers", 3), ("e", 4)]);
+        let matches: Vec<u32> = matcher.find_iter("ushers").map(|m| *m.value).collect();
+        assert_eq!(matches, vec![1, 2, 4, 3]);
+    }
+
+    #[test]
+    fn test_first_and_last() {
+        let matcher = words();
+        let first = matcher.first("zeightwo").unwrap();
+        assert_eq!((first.start, *first.value), (1, 82));
+        let last = matcher.last("zeightwo").unwrap();
+        assert_eq!((last.start, *last.value), (5, 2));
+        assert_eq!(*matcher.first("none of these").unwrap().value, 1);
+        assert_eq!(*matcher.last("none of these").unwrap().value, 0);
+        assert!(matcher.last("xyz").is_none());
+    }
+
+    #[test]
+    fn test_roman_numerals() {
+        let matcher = Matcher::new([("I", 1), ("IV", 4), ("V", 5), ("IX", 9), ("X", 10)]);
+        let values: Vec<u32> = matcher.find_iter("XIV").map(|m| *m.value).collect();
+        assert_eq!(values, vec![10, 1, 4, 5]);
+    }
+}
@@ -0,0 +1,227 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::grid::Grid;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub expected: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+        if self.token.is_empty() {
+            write!(f, "end of line")
+        } else {
+            write!(f, "'{}'", self.token.escape_debug())
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// The whole puzzle input. Parsers hand it slices of that input so errors can
+/// be reported with a line and column without tracking positions by hand.
+#[derive(Debug, Clone, Copy)]
+pub struct Context<'a> {
+    input: &'a str,
+}
+
+impl<'a> Context<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Context { input }
+    }
+
+    /// Builds an error for `token`, which must be a slice of the input.
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        let offset = (token.as_ptr() as usize)
+            .saturating_sub(self.input.as_ptr() as usize)
+            .min(self.input.len());
+        let before = self.input.get(..offset).unwrap_or_default();
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            token: token.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Builds an error for a token missing after `s`, e.g. in a truncated line.
+    pub fn missing(&self, s: &str, expected: impl Into<String>) -> ParseError {
+        self.error(&s[s.len()..], expected)
+    }
+
+    pub fn number<T: FromStr>(&self, token: &'a str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, "a number"))
+    }
+
+    pub fn split_once(
+        &self,
+        s: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error(s, format!("'{}'", delimiter.escape_debug())))
+    }
+
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix).ok_or_else(|| {
+            let end = s
+                .char_indices()
+                .nth(prefix.chars().count())
+                .map_or(s.len(), |(i, _)| i);
+            self.error(&s[..end], format!("'{}'", prefix.escape_debug()))
+        })
+    }
+
+    pub fn strip_suffix(&self, s: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+        s.strip_suffix(suffix).ok_or_else(|| {
+            let start = s
+                .char_indices()
+                .nth_back(suffix.chars().count() - 1)
+                .map_or(0, |(i, _)| i);
+            self.error(&s[start..], format!("'{}'", suffix.escape_debug()))
+        })
+    }
+
+    /// Takes the next token from `tokens`, reporting it as missing after `after`.
+    pub fn next(
+        &self,
+        tokens: &mut impl Iterator<Item = &'a str>,
+        after: &'a str,
+        expected: &str,
+    ) -> Result<&'a str, ParseError> {
+        tokens.next().ok_or_else(|| self.missing(after, expected))
+    }
+
+    /// Parses each character of `block` into a cell, row by row. Every row
+    /// must be as wide as the first, which must not be empty.
+    pub fn grid<T>(
+        &self,
+        block: &'a str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let first = block.lines().next().unwrap_or(block);
+        if first.is_empty() {
+            return Err(self.missing(first, "a row"));
+        }
+        let width = first.chars().count();
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for line in block.lines() {
+            for (j, c) in line.char_indices() {
+                let token = &line[j..j + c.len_utf8()];
+                cells.push(cell(c).ok_or_else(|| self.error(token, expected))?);
+            }
+            height += 1;
+            if cells.len() != width * height {
+                return Err(self.error(line, format!("a row of {width} cells")));
+            }
+        }
+        Ok(Grid::new(width, height, cells))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::*;
+
+    fn input() -> &'static str {
+        "seeds: 1 2
+x=3, y=four"
+    }
+
+    #[test]
+    fn test_error_position() {
+        let input = input();
+        let ctx = Context::new(input);
+        let (_, y) = ctx.split_once(input, "y=").unwrap();
+        let error = ctx.number::<i32>(y).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError {
+                line: 2,
+                column: 8,
+                token: "four".to_string(),
+                expected: "a number".to_string(),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 8: expected a number, found 'four'"
+        );
+    }
+
+    #[test]
+    fn test_missing() {
+        let input = input();
+        let ctx = Context::new(input);
+        let first = input.lines().next().unwrap();
+        let mut tokens = first.split(' ').skip(3);
+        let error = ctx.next(&mut tokens, first, "a seed").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 11));
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 11: expected a seed, found end of line"
+        );
+    }
+
+    #[test]
+    fn test_strip() {
+        let input = input();
+        let ctx = Context::new(input);
+        assert_eq!(ctx.strip_prefix(input, "seeds: ").unwrap(), &input[7..]);
+        let error = ctx.strip_prefix(input, "time: ").unwrap_err();
+        assert_eq!(error.token, "seeds:");
+        let error = ctx.strip_suffix(input, "}").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (2, 11, "r")
+        );
+    }
+
+    #[test]
+    fn test_split_once() {
+        let input = input();
+        let ctx = Context::new(input);
+        let error = ctx.split_once(input, "\n\n").unwrap_err();
+        assert_eq!(error.expected, "'\\n\\n'");
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected '\\n\\n', found 'seeds: 1 2\\nx=3, y=four'"
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let input = "#.\n.x";
+        let ctx = Context::new(input);
+        let error = ctx
+            .grid(input, "'#' or '.'", |c| match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            })
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+
+        for input in ["", "\n#."] {
+            let error = Context::new(input)
+                .grid(input, "'#'", |c| (c == '#').then_some(()))
+                .unwrap_err();
+            assert_eq!((error.line, error.column), (1, 1));
+            assert_eq!(error.expected, "a row");
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use std::fmt;
+use std::process;
+use std::str::FromStr;
+
+use crate::input;
+use crate::parse::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "A" => Ok(Part::A),
+            "b" | "B" => Ok(Part::B),
+            _ => Err(format!("invalid part '{s}', expected 'a' or 'b'")),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::A => write!(f, "a"),
+            Part::B => write!(f, "b"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String),
+    None,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::None => write!(f, "none"),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident, $target:ty, $($source:ty),*) => {
+        $(
+            impl From<$source> for Answer {
+                fn from(value: $source) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(Signed, i64, i32, i64);
+impl_from!(Unsigned, u64, u32, u64, usize);
+
+/// Answers that do not fit a `u64` are given as text.
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        u64::try_from(value).map_or_else(|_| Answer::Text(value.to_string()), Answer::Unsigned)
+    }
+}
+
+/// Answers that do not fit an `i64` are given as text.
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        i64::try_from(value).map_or_else(|_| Answer::Text(value.to_string()), Answer::Signed)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Answer::None, Into::into)
+    }
+}
+
+/// A day's puzzle, split into a parse step shared by both parts and the two
+/// parts themselves.
+pub trait Solution {
+    const DAY: u32;
+
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+
+    fn part_a(parsed: &Self::Parsed<'_>) -> Answer;
+
+    fn part_b(parsed: &Self::Parsed<'_>) -> Answer;
+
+    fn solve(input: &str, part: Part) -> Result<Answer, ParseError> {
+        let parsed = Self::parse(input)?;
+        Ok(match part {
+            Part::A => Self::part_a(&parsed),
+            Part::B => Self::part_b(&parsed),
+        })
+    }
+}
+
+/// Entry point of the day binaries: reads the input named on the command line
+/// and prints both answers, or the parse error and a failing exit code.
+pub fn run<S: Solution>(bundled: &str) {
+    let input = input::from_args(S::DAY, bundled);
+    match S::parse(&input) {
+        Ok(parsed) => {
+            println!("{}", S::part_a(&parsed));
+            println!("{}", S::part_b(&parsed));
+        }
+        Err(e) => exit_with(e),
+    };
+}
+
+fn exit_with(e: ParseError) -> ! {
+    eprintln!("error: {e}");
+    process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::Context;
+    use crate::solution::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u32 = 0;
+
+        type Parsed<'a> = Vec<i32>;
+
+        fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            let ctx = Context::new(input);
+            input.split(',').map(|n| ctx.number(n)).collect()
+        }
+
+        fn part_a(parsed: &Self::Parsed<'_>) -> Answer {
+            parsed.iter().sum::<i32>().into()
+        }
+
+        fn part_b(parsed: &Self::Parsed<'_>) -> Answer {
+            parsed.iter().max().copied().into()
+        }
+    }
+
+    #[test]
+    fn test_answer_from() {
+        assert_eq!(Answer::from(-3i32), Answer::Signed(-3));
+        assert_eq!(Answer::from(3usize), Answer::Unsigned(3));
+        assert_eq!(Answer::from(None::<u32>), Answer::None);
+        assert_eq!(Answer::from(Some(7u32)).to_string(), "7");
+        assert_eq!(Answer::from(5u128), Answer::Unsigned(5));
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from(-5i128), Answer::Signed(-5));
+        assert_eq!(Answer::from(i128::MIN).to_string(), i128::MIN.to_string());
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(Sum::solve("1,-4,2", Part::A), Ok(Answer::Signed(-1)));
+        assert_eq!(Sum::solve("1,-4,2", Part::B), Ok(Answer::Signed(2)));
+        assert_eq!(Sum::solve("1,x", Part::A).unwrap_err().column, 3);
+    }
+}
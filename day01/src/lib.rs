@@ -0,0 +1,123 @@
+use common::matcher::Matcher;
+use common::parse::{Context, ParseError};
+use common::solution::{Answer, Solution};
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// The digits 1 to 9, matched as themselves.
+pub fn digits() -> Matcher<u32> {
+    Matcher::new((1..=9).map(|digit| (digit.to_string(), digit)))
+}
+
+/// The digits 1 to 9, matched as themselves or spelled out in English.
+pub fn digits_and_words() -> Matcher<u32> {
+    let words = (1..=9).map(|digit| (WORDS[digit as usize - 1].to_string(), digit));
+    Matcher::new((1..=9).map(|digit| (digit.to_string(), digit)).chain(words))
+}
+
+/// The first and last match in `line`, read as a two digit number.
+fn calibration_value(line: &str, matcher: &Matcher<u32>) -> Option<u32> {
+    Some(matcher.first(line)?.value * 10 + matcher.last(line)?.value)
+}
+
+/// Sums the calibration value of each line. `expected` describes the
+/// vocabulary of `matcher` in errors.
+pub fn calibration_sum(
+    input: &str,
+    matcher: &Matcher<u32>,
+    expected: &str,
+) -> Result<u32, ParseError> {
+    let ctx = Context::new(input);
+    input
+        .lines()
+        .map(|line| calibration_value(line, matcher).ok_or_else(|| ctx.error(line, expected)))
+        .sum()
+}
+
+/// The lines of the document, each holding at least a digit or a digit word.
+fn parse_input(input: &str) -> Result<Vec<&str>, ParseError> {
+    let ctx = Context::new(input);
+    let matcher = digits_and_words();
+    input
+        .lines()
+        .map(|line| match matcher.first(line) {
+            Some(_) => Ok(line),
+            None => Err(ctx.error(line, "a digit or digit word")),
+        })
+        .collect()
+}
+
+pub fn day1a(input: &str) -> Result<u32, ParseError> {
+    calibration_sum(input, &digits(), "a digit")
+}
+
+pub fn day1b(input: &str) -> Result<u32, ParseError> {
+    calibration_sum(input, &digits_and_words(), "a digit or digit word")
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    /// None if a line only spells its digits out.
+    fn part_a(lines: &Self::Parsed<'_>) -> Answer {
+        let matcher = digits();
+        lines
+            .iter()
+            .map(|line| calibration_value(line, &matcher))
+            .sum::<Option<u32>>()
+            .into()
+    }
+
+    fn part_b(lines: &Self::Parsed<'_>) -> Answer {
+        let matcher = digits_and_words();
+        lines
+            .iter()
+            .map(|line| calibration_value(line, &matcher))
+            .sum::<Option<u32>>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn input() -> &'static str {
+        include_str!("../test.txt")
+    }
+
+    fn input_b() -> &'static str {
+        include_str!("../test_b.txt")
+    }
+
+    #[test]
+    fn test_1a() {
+        let input = input();
+        assert_eq!(day1a(input), Ok(142));
+        assert!(day1a(input_b()).is_err());
+    }
+
+    #[test]
+    fn test_1b() {
+        assert_eq!(day1b(input()), Ok(142));
+        assert_eq!(day1b(input_b()), Ok(281));
+    }
+
+    #[test]
+    fn test_1_solution() {
+        let lines = Day01::parse(input_b()).unwrap();
+        assert_eq!(Day01::part_a(&lines), Answer::None);
+        assert_eq!(Day01::part_b(&lines), Answer::Unsigned(281));
+        assert!(Day01::parse("abc").is_err());
+    }
+}
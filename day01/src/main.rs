@@ -0,0 +1,3 @@
+fn main() {
+    common::solution::run::<day01::Day01>(include_str!("../input.txt"));
+}
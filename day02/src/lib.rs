@@ -0,0 +1,180 @@
+use common::parse::{Context, ParseError};
+use common::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+/// A number of cubes of each colour.
+pub type Bag<'a> = HashMap<&'a str, u32>;
+
+/// The cubes shown at once, in the order they were listed.
+pub type Draw<'a> = Vec<(&'a str, u32)>;
+
+/// Whether `bag` has at least `n` cubes of `color`.
+fn holds(bag: &Bag, color: &str, n: u32) -> bool {
+    n <= bag.get(color).copied().unwrap_or(0)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game<'a> {
+    pub id: u32,
+    pub draws: Vec<Draw<'a>>,
+}
+
+impl<'a> Game<'a> {
+    /// Whether `bag` holds enough cubes of each colour for every draw.
+    pub fn is_possible(&self, bag: &Bag) -> bool {
+        self.draws
+            .iter()
+            .flatten()
+            .all(|&(color, n)| holds(bag, color, n))
+    }
+
+    /// The fewest cubes of each colour the game could have been played with.
+    pub fn minimum_bag(&self) -> Bag<'a> {
+        let mut bag = Bag::new();
+        for &(color, n) in self.draws.iter().flatten() {
+            let max = bag.entry(color).or_insert(0);
+            *max = (*max).max(n);
+        }
+        bag
+    }
+
+    /// The product of the counts of the minimum bag. Colours the game never
+    /// shows are left out.
+    pub fn power(&self) -> u32 {
+        self.minimum_bag().values().product()
+    }
+}
+
+/// The ids of the games possible with each of `bags`, in the same order.
+pub fn possible_games(games: &[Game], bags: &[Bag]) -> Vec<Vec<u32>> {
+    let minimum_bags: Vec<(u32, Bag)> = games
+        .iter()
+        .map(|game| (game.id, game.minimum_bag()))
+        .collect();
+    bags.iter()
+        .map(|bag| {
+            minimum_bags
+                .iter()
+                .filter(|(_, minimum)| minimum.iter().all(|(color, &n)| holds(bag, color, n)))
+                .map(|&(id, _)| id)
+                .collect()
+        })
+        .collect()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Game<'_>>, ParseError> {
+    let ctx = Context::new(input);
+    input
+        .lines()
+        .map(|line| {
+            let (id, draws) = ctx.split_once(line, ": ")?;
+            let draws = draws
+                .split("; ")
+                .map(|draw| {
+                    draw.split(", ")
+                        .map(|cubes| {
+                            let (n, color) = ctx.split_once(cubes, " ")?;
+                            Ok((color, ctx.number(n)?))
+                        })
+                        .collect()
+                })
+                .collect::<Result<_, _>>()?;
+            Ok(Game {
+                id: ctx.number(ctx.strip_prefix(id, "Game ")?)?,
+                draws,
+            })
+        })
+        .collect()
+}
+
+/// Sums the ids of the games possible with 12 red, 13 green and 14 blue cubes.
+fn possible_ids_sum(games: &[Game]) -> u32 {
+    let bag = Bag::from([("red", 12), ("green", 13), ("blue", 14)]);
+    possible_games(games, &[bag])[0].iter().sum()
+}
+
+fn power_sum(games: &[Game]) -> u32 {
+    games.iter().map(Game::power).sum()
+}
+
+pub fn day2a(input: &str) -> Result<u32, ParseError> {
+    Ok(possible_ids_sum(&parse_input(input)?))
+}
+
+pub fn day2b(input: &str) -> Result<u32, ParseError> {
+    Ok(power_sum(&parse_input(input)?))
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+
+    type Parsed<'a> = Vec<Game<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a(games: &Self::Parsed<'_>) -> Answer {
+        possible_ids_sum(games).into()
+    }
+
+    fn part_b(games: &Self::Parsed<'_>) -> Answer {
+        power_sum(games).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn input() -> &'static str {
+        include_str!("../test.txt")
+    }
+
+    #[test]
+    fn test_2a() {
+        let input = input();
+        assert_eq!(day2a(input), Ok(8));
+    }
+
+    #[test]
+    fn test_2b() {
+        let input = input();
+        assert_eq!(day2b(input), Ok(2286));
+    }
+
+    #[test]
+    fn test_2_game() {
+        let games = parse_input(input()).unwrap();
+        let game = &games[0];
+        assert_eq!(game.id, 1);
+        assert_eq!(game.draws[1], vec![("red", 1), ("green", 2), ("blue", 6)]);
+        assert_eq!(
+            game.minimum_bag(),
+            Bag::from([("red", 4), ("green", 2), ("blue", 6)])
+        );
+        assert_eq!(game.power(), 48);
+        assert!(game.is_possible(&Bag::from([("red", 4), ("green", 2), ("blue", 6)])));
+        assert!(!game.is_possible(&Bag::from([("red", 4), ("blue", 6)])));
+
+        let game = &parse_input("Game 7: 2 cyan, 0 red; 3 magenta").unwrap()[0];
+        assert!(game.is_possible(&Bag::from([("cyan", 2), ("magenta", 3)])));
+        assert_eq!(game.power(), 0);
+    }
+
+    #[test]
+    fn test_2_possible_games() {
+        let games = parse_input(input()).unwrap();
+        let bags = [
+            Bag::from([("red", 12), ("green", 13), ("blue", 14)]),
+            Bag::from([("red", 20), ("green", 13), ("blue", 15)]),
+            Bag::new(),
+        ];
+        assert_eq!(
+            possible_games(&games, &bags),
+            vec![vec![1, 2, 5], vec![1, 2, 3, 4, 5], vec![]]
+        );
+    }
+}
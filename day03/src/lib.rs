@@ -0,0 +1,237 @@
+use common::grid::{Grid, Pos};
+use common::parse::ParseError;
+use common::solution::{Answer, Solution};
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    pub value: u32,
+    pub row: usize,
+    /// The columns of the digits.
+    pub cols: Range<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub symbol: char,
+    pub pos: Pos,
+}
+
+/// The numbers and symbols of an engine schematic, with an index from each
+/// cell to what it holds to look up neighbours.
+#[derive(Debug, Clone)]
+pub struct Schematic {
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<Symbol>,
+    /// The index of the number or symbol in each cell.
+    cells: Grid<Cell>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Number(usize),
+    Symbol(usize),
+}
+
+impl Schematic {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input, "a character", Some)?;
+        let mut cells = Grid::filled(grid.width(), grid.height(), Cell::Empty);
+        let (mut numbers, mut symbols) = (Vec::new(), Vec::new());
+        for (i, row) in grid.rows().enumerate() {
+            let mut j = 0;
+            while j < row.len() {
+                let start = j;
+                let mut value = 0;
+                while let Some(digit) = row.get(j).and_then(|c| c.to_digit(10)) {
+                    value = value * 10 + digit;
+                    cells[(i, j)] = Cell::Number(numbers.len());
+                    j += 1;
+                }
+                if j > start {
+                    numbers.push(Number {
+                        value,
+                        row: i,
+                        cols: start..j,
+                    });
+                    continue;
+                }
+                if row[j] != '.' {
+                    cells[(i, j)] = Cell::Symbol(symbols.len());
+                    symbols.push(Symbol {
+                        symbol: row[j],
+                        pos: (i, j),
+                    });
+                }
+                j += 1;
+            }
+        }
+        Ok(Schematic {
+            numbers,
+            symbols,
+            cells,
+        })
+    }
+
+    /// The symbols around `number`, in reading order.
+    pub fn symbols_adjacent_to(&self, number: &Number) -> Vec<&Symbol> {
+        let rows = number.row.saturating_sub(1)..=number.row + 1;
+        let cols = number.cols.start.saturating_sub(1)..=number.cols.end;
+        rows.flat_map(|row| cols.clone().map(move |col| (row, col)))
+            .filter_map(|pos| match self.cells.get(pos) {
+                Some(&Cell::Symbol(i)) => Some(&self.symbols[i]),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The numbers around `symbol`, in reading order and each once.
+    pub fn numbers_adjacent_to(&self, symbol: &Symbol) -> Vec<&Number> {
+        let numbers: BTreeSet<usize> = self
+            .cells
+            .neighbours8(symbol.pos)
+            .filter_map(|pos| match self.cells[pos] {
+                Cell::Number(i) => Some(i),
+                _ => None,
+            })
+            .collect();
+        numbers.into_iter().map(|i| &self.numbers[i]).collect()
+    }
+
+    /// The numbers next to at least one symbol matching `is_symbol`.
+    pub fn part_numbers<'a>(
+        &'a self,
+        is_symbol: impl Fn(char) -> bool + 'a,
+    ) -> impl Iterator<Item = &'a Number> + 'a {
+        self.numbers.iter().filter(move |number| {
+            self.symbols_adjacent_to(number)
+                .iter()
+                .any(|symbol| is_symbol(symbol.symbol))
+        })
+    }
+
+    /// The symbols matching `is_symbol` with exactly `k` numbers around them,
+    /// along with those numbers.
+    pub fn gears(
+        &self,
+        is_symbol: impl Fn(char) -> bool,
+        k: usize,
+    ) -> Vec<(&Symbol, Vec<&Number>)> {
+        self.symbols
+            .iter()
+            .filter(|symbol| is_symbol(symbol.symbol))
+            .map(|symbol| (symbol, self.numbers_adjacent_to(symbol)))
+            .filter(|(_, numbers)| numbers.len() == k)
+            .collect()
+    }
+}
+
+fn part_number_sum(schematic: &Schematic) -> u32 {
+    schematic
+        .part_numbers(|_| true)
+        .map(|number| number.value)
+        .sum()
+}
+
+fn gear_ratio_sum(schematic: &Schematic) -> u32 {
+    schematic
+        .gears(|c| c == '*', 2)
+        .iter()
+        .map(|(_, numbers)| numbers.iter().map(|number| number.value).product::<u32>())
+        .sum()
+}
+
+pub fn day3a(input: &str) -> Result<u32, ParseError> {
+    Ok(part_number_sum(&Schematic::parse(input)?))
+}
+
+pub fn day3b(input: &str) -> Result<u32, ParseError> {
+    Ok(gear_ratio_sum(&Schematic::parse(input)?))
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+
+    type Parsed<'a> = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Schematic::parse(input)
+    }
+
+    fn part_a(schematic: &Self::Parsed<'_>) -> Answer {
+        part_number_sum(schematic).into()
+    }
+
+    fn part_b(schematic: &Self::Parsed<'_>) -> Answer {
+        gear_ratio_sum(schematic).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn input() -> &'static str {
+        include_str!("../test.txt")
+    }
+
+    #[test]
+    fn test_3a() {
+        let input = input();
+        assert_eq!(day3a(input), Ok(4361));
+    }
+
+    #[test]
+    fn test_3b() {
+        let input = input();
+        assert_eq!(day3b(input), Ok(467835));
+    }
+
+    #[test]
+    fn test_3_schematic() {
+        let schematic = Schematic::parse(input()).unwrap();
+        assert_eq!(schematic.numbers.len(), 10);
+        assert_eq!(
+            schematic.numbers[1],
+            Number {
+                value: 114,
+                row: 0,
+                cols: 5..8
+            }
+        );
+        assert_eq!(schematic.symbols.len(), 6);
+        assert_eq!(
+            schematic.symbols_adjacent_to(&schematic.numbers[0]),
+            vec![&Symbol {
+                symbol: '*',
+                pos: (1, 3)
+            }]
+        );
+        assert!(schematic
+            .symbols_adjacent_to(&schematic.numbers[1])
+            .is_empty());
+        let values: Vec<u32> = schematic
+            .numbers_adjacent_to(&schematic.symbols[0])
+            .iter()
+            .map(|number| number.value)
+            .collect();
+        assert_eq!(values, vec![467, 35]);
+
+        let values: Vec<u32> = schematic
+            .part_numbers(|c| c == '#' || c == '$')
+            .map(|number| number.value)
+            .collect();
+        assert_eq!(values, vec![633, 664]);
+        let gears: Vec<Pos> = schematic
+            .gears(|c| c != '*', 1)
+            .iter()
+            .map(|(symbol, _)| symbol.pos)
+            .collect();
+        assert_eq!(gears, vec![(3, 6), (5, 5), (8, 3)]);
+        assert_eq!(schematic.gears(|c| c == '*', 1).len(), 1);
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    common::solution::run::<day03::Day03>(include_str!("../input.txt"));
+}
@@ -0,0 +1,167 @@
+use common::parse::{Context, ParseError};
+use common::solution::{Answer, Solution};
+
+/// A scratchcard, its numbers stored as bitsets so that matching them is a
+/// single `&`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Card {
+    pub id: u32,
+    winning: u128,
+    mine: u128,
+}
+
+impl Card {
+    /// How many of my numbers are winning numbers.
+    pub fn matches(&self) -> u32 {
+        (self.winning & self.mine).count_ones()
+    }
+
+    pub fn points(&self) -> u32 {
+        match self.matches() {
+            0 => 0,
+            n => 1 << (n - 1),
+        }
+    }
+}
+
+fn parse_numbers(ctx: &Context, numbers: &str) -> Result<u128, ParseError> {
+    numbers
+        .split_whitespace()
+        .try_fold(0, |set, n| match ctx.number::<u32>(n)? {
+            n if n < u128::BITS => Ok(set | 1 << n),
+            _ => Err(ctx.error(n, "a number below 128")),
+        })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Card>, ParseError> {
+    let ctx = Context::new(input);
+    input
+        .lines()
+        .map(|line| {
+            let (id, numbers) = ctx.split_once(line, ": ")?;
+            let (winning_numbers, my_numbers) = ctx.split_once(numbers, " | ")?;
+            Ok(Card {
+                id: ctx.number(ctx.strip_prefix(id, "Card")?.trim_start())?,
+                winning: parse_numbers(&ctx, winning_numbers)?,
+                mine: parse_numbers(&ctx, my_numbers)?,
+            })
+        })
+        .collect()
+}
+
+/// The copies of each card won by playing a pile of cards in order, where
+/// each copy of a card with `n` matches wins a copy of each of the next `n`
+/// cards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cascade<'a> {
+    cards: &'a [Card],
+    /// The copies of each card, the original included.
+    pub copies: Vec<u64>,
+}
+
+impl<'a> Cascade<'a> {
+    pub fn run(cards: &'a [Card]) -> Self {
+        let mut copies = vec![1; cards.len()];
+        for (i, card) in cards.iter().enumerate() {
+            let won = (i + 1..=i + card.matches() as usize).take_while(|&j| j < cards.len());
+            for j in won {
+                copies[j] += copies[i];
+            }
+        }
+        Cascade { cards, copies }
+    }
+
+    pub fn total(&self) -> u64 {
+        self.copies.iter().sum()
+    }
+
+    /// The card whose copies won the most cards, with how many they won. The
+    /// first such card if several tie, `None` if no card won anything.
+    pub fn top_contributor(&self) -> Option<(&'a Card, u64)> {
+        self.cards
+            .iter()
+            .zip(&self.copies)
+            .map(|(card, copies)| (card, copies * u64::from(card.matches())))
+            .filter(|&(_, won)| won > 0)
+            .rev()
+            .max_by_key(|&(_, won)| won)
+    }
+}
+
+fn points(cards: &[Card]) -> u32 {
+    cards.iter().map(Card::points).sum()
+}
+
+fn total_cards(cards: &[Card]) -> u64 {
+    Cascade::run(cards).total()
+}
+
+pub fn day4a(input: &str) -> Result<u32, ParseError> {
+    Ok(points(&parse_input(input)?))
+}
+
+pub fn day4b(input: &str) -> Result<u64, ParseError> {
+    Ok(total_cards(&parse_input(input)?))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+
+    type Parsed<'a> = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a(cards: &Self::Parsed<'_>) -> Answer {
+        points(cards).into()
+    }
+
+    fn part_b(cards: &Self::Parsed<'_>) -> Answer {
+        total_cards(cards).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn input() -> &'static str {
+        "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"
+    }
+
+    #[test]
+    fn test_4a() {
+        let input = input();
+        assert_eq!(day4a(input), Ok(13));
+    }
+
+    #[test]
+    fn test_4b() {
+        let input = input();
+        assert_eq!(day4b(input), Ok(30));
+    }
+
+    #[test]
+    fn test_4_cascade() {
+        let cards = parse_input(input()).unwrap();
+        let matches: Vec<u32> = cards.iter().map(Card::matches).collect();
+        assert_eq!(matches, vec![4, 2, 2, 1, 0, 0]);
+
+        let cascade = Cascade::run(&cards);
+        assert_eq!(cascade.copies, vec![1, 2, 4, 8, 14, 1]);
+        assert_eq!(cascade.total(), 30);
+        let (card, won) = cascade.top_contributor().unwrap();
+        assert_eq!((card.id, won), (3, 8));
+        assert_eq!(Cascade::run(&cards[4..]).top_contributor(), None);
+
+        assert!(parse_input("Card 1: 128 | 1").is_err());
+    }
+}
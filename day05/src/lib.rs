@@ -0,0 +1,395 @@
+use common::parse::{Context, ParseError};
+use common::solution::{Answer, Solution};
+use std::ops::Range;
+
+#[derive(Debug, Clone)]
+pub struct XtoYMaps(Vec<Map>);
+
+#[derive(Debug, Clone)]
+struct Map {
+    dest_start: i64,
+    src_start: i64,
+    length: i64,
+}
+
+/// Inputs in `start..end` are mapped to `input + offset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Piece {
+    pub start: i64,
+    pub end: i64,
+    pub offset: i64,
+}
+
+/// A piecewise-linear function that maps every input outside its pieces to
+/// itself. The pieces are sorted and do not overlap.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Piecewise {
+    pieces: Vec<Piece>,
+}
+
+impl Piecewise {
+    /// Builds the function from non-overlapping pieces, dropping the pieces
+    /// that map to the input itself and merging neighbours with the same
+    /// offset.
+    pub fn new(mut pieces: Vec<Piece>) -> Self {
+        pieces.sort_by_key(|piece| piece.start);
+        let mut merged: Vec<Piece> = Vec::new();
+        for piece in pieces {
+            if piece.offset == 0 || piece.start >= piece.end {
+                continue;
+            }
+            match merged.last_mut() {
+                Some(last) if last.end == piece.start && last.offset == piece.offset => {
+                    last.end = piece.end
+                }
+                _ => merged.push(piece),
+            }
+        }
+        Piecewise { pieces: merged }
+    }
+
+    pub fn pieces(&self) -> &[Piece] {
+        &self.pieces
+    }
+
+    pub fn apply(&self, input: i64) -> i64 {
+        let i = self.pieces.partition_point(|piece| piece.end <= input);
+        match self.pieces.get(i) {
+            Some(piece) if piece.start <= input => input + piece.offset,
+            _ => input,
+        }
+    }
+
+    /// The pieces together with the gaps between them, which have an offset
+    /// of zero, covering every input.
+    fn segments(&self) -> Vec<Piece> {
+        let mut segments = Vec::new();
+        let mut start = i64::MIN;
+        for &piece in &self.pieces {
+            if start < piece.start {
+                segments.push(Piece {
+                    start,
+                    end: piece.start,
+                    offset: 0,
+                });
+            }
+            segments.push(piece);
+            start = piece.end;
+        }
+        if start < i64::MAX {
+            segments.push(Piece {
+                start,
+                end: i64::MAX,
+                offset: 0,
+            });
+        }
+        segments
+    }
+
+    /// The image of `range`, as one range for each segment it overlaps.
+    pub fn image(&self, range: Range<i64>) -> impl Iterator<Item = Range<i64>> {
+        self.segments().into_iter().filter_map(move |segment| {
+            let start = range.start.max(segment.start);
+            let end = range.end.min(segment.end);
+            (start < end).then(|| start + segment.offset..end + segment.offset)
+        })
+    }
+
+    /// The function that applies `self` and then `next`.
+    pub fn then(&self, next: &Piecewise) -> Piecewise {
+        let mut pieces = Vec::new();
+        for first in self.segments() {
+            for second in next.segments() {
+                // the inputs of `first` that it maps into `second`
+                let start = first.start.max(second.start.saturating_sub(first.offset));
+                let end = first.end.min(second.end.saturating_sub(first.offset));
+                if start < end {
+                    pieces.push(Piece {
+                        start,
+                        end,
+                        offset: first.offset + second.offset,
+                    });
+                }
+            }
+        }
+        Piecewise::new(pieces)
+    }
+}
+
+impl XtoYMaps {
+    pub fn piecewise(&self) -> Piecewise {
+        Piecewise::new(
+            self.0
+                .iter()
+                .map(|map| Piece {
+                    start: map.src_start,
+                    end: map.src_start + map.length,
+                    offset: map.dest_start - map.src_start,
+                })
+                .collect(),
+        )
+    }
+}
+
+/// All the maps composed into a single function from seed to location.
+pub fn seed_to_location(x_to_y_maps: &[XtoYMaps]) -> Piecewise {
+    x_to_y_maps
+        .iter()
+        .fold(Piecewise::default(), |function, maps| {
+            function.then(&maps.piecewise())
+        })
+}
+
+/// The seeds, at least one of them since both parts take the lowest location.
+fn parse_seeds(ctx: &Context, seeds_raw: &str) -> Result<Vec<i64>, ParseError> {
+    let seeds = ctx
+        .split_once(seeds_raw, ": ")?
+        .1
+        .split_whitespace()
+        .map(|x| ctx.number::<i64>(x))
+        .collect::<Result<Vec<_>, _>>()?;
+    if seeds.is_empty() {
+        return Err(ctx.missing(seeds_raw, "a seed"));
+    }
+    Ok(seeds)
+}
+
+/// Reads the seeds as pairs of a start and a length, as part b does. Fails
+/// with the index of the first length that is missing or not positive.
+fn seed_ranges(seeds: &[i64]) -> Result<Vec<Range<i64>>, usize> {
+    seeds
+        .chunks(2)
+        .enumerate()
+        .map(|(i, chunk)| match *chunk {
+            [start, length] if length > 0 => Ok(start..start + length),
+            _ => Err(2 * i + 1),
+        })
+        .collect()
+}
+
+fn parse_maps(ctx: &Context, maps_raw: &str) -> Result<Vec<XtoYMaps>, ParseError> {
+    let mut x_to_y_maps: Vec<XtoYMaps> = Vec::new();
+    for map in maps_raw.split("\n\n") {
+        let map_content = ctx.split_once(map, "\n")?.1;
+        let mut maps = Vec::new();
+        for line in map_content.lines() {
+            let mut split_line = line.split_whitespace();
+            let mut next_number =
+                |expected| ctx.number::<i64>(ctx.next(&mut split_line, line, expected)?);
+            maps.push(Map {
+                dest_start: next_number("a destination range start")?,
+                src_start: next_number("a source range start")?,
+                length: next_number("a range length")?,
+            })
+        }
+        x_to_y_maps.push(XtoYMaps(maps))
+    }
+    Ok(x_to_y_maps)
+}
+
+fn parse_input(input: &str) -> Result<(Vec<i64>, Vec<XtoYMaps>), ParseError> {
+    let ctx = Context::new(input);
+    let (seeds_raw, maps_raw) = ctx.split_once(input, "\n\n")?;
+    let seeds = parse_seeds(&ctx, seeds_raw)?;
+    let x_to_y_maps = parse_maps(&ctx, maps_raw)?;
+    Ok((seeds, x_to_y_maps))
+}
+
+fn lowest_location(seeds: &[i64], x_to_y_maps: &[XtoYMaps]) -> i64 {
+    let seed_to_location = seed_to_location(x_to_y_maps);
+    seeds
+        .iter()
+        .map(|&seed| seed_to_location.apply(seed))
+        .min()
+        .unwrap()
+}
+
+/// Pushes the seed ranges through one map at a time, splitting them wherever
+/// they cross the edge of a map.
+fn lowest_location_of_ranges(ranges: &[Range<i64>], x_to_y_maps: &[XtoYMaps]) -> i64 {
+    let mut ranges = ranges.to_vec();
+    for maps in x_to_y_maps {
+        let piecewise = maps.piecewise();
+        ranges = ranges
+            .into_iter()
+            .flat_map(|range| piecewise.image(range))
+            .collect();
+    }
+    ranges.iter().map(|range| range.start).min().unwrap()
+}
+
+pub fn day5a(input: &str) -> Result<i64, ParseError> {
+    let (seeds, x_to_y_maps) = parse_input(input)?;
+    Ok(lowest_location(&seeds, &x_to_y_maps))
+}
+
+pub fn day5b(input: &str) -> Result<i64, ParseError> {
+    let ctx = Context::new(input);
+    let (seeds, x_to_y_maps) = parse_input(input)?;
+    let ranges = seed_ranges(&seeds).map_err(|i| {
+        // the seeds parsed, so the line splits the same way as in parse_seeds
+        let seeds_raw = input.split_once("\n\n").map_or(input, |(raw, _)| raw);
+        let numbers = seeds_raw
+            .split_once(": ")
+            .map_or("", |(_, numbers)| numbers);
+        match numbers.split_whitespace().nth(i) {
+            Some(token) => ctx.error(token, "a positive seed range length"),
+            None => ctx.missing(seeds_raw, "a seed range length"),
+        }
+    })?;
+    Ok(lowest_location_of_ranges(&ranges, &x_to_y_maps))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+
+    type Parsed<'a> = (Vec<i64>, Vec<XtoYMaps>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a((seeds, x_to_y_maps): &Self::Parsed<'_>) -> Answer {
+        lowest_location(seeds, x_to_y_maps).into()
+    }
+
+    fn part_b((seeds, x_to_y_maps): &Self::Parsed<'_>) -> Answer {
+        seed_ranges(seeds)
+            .ok()
+            .map(|ranges| lowest_location_of_ranges(&ranges, x_to_y_maps))
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn input() -> &'static str {
+        "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4"
+    }
+
+    #[test]
+    fn test_5a() {
+        let input = input();
+        assert_eq!(day5a(input), Ok(35));
+    }
+
+    #[test]
+    fn test_5b() {
+        let input = input();
+        assert_eq!(day5b(input), Ok(46));
+    }
+
+    #[test]
+    fn test_5_piecewise() {
+        let f = Piecewise::new(vec![
+            Piece {
+                start: 10,
+                end: 20,
+                offset: 5,
+            },
+            Piece {
+                start: 0,
+                end: 10,
+                offset: 5,
+            },
+        ]);
+        assert_eq!(f.pieces().len(), 1);
+        assert_eq!(
+            (f.apply(-1), f.apply(0), f.apply(19), f.apply(20)),
+            (-1, 5, 24, 20)
+        );
+        let image: Vec<_> = f.image(15..25).collect();
+        assert_eq!(image, vec![20..25, 20..25]);
+
+        let g = Piecewise::new(vec![Piece {
+            start: 22,
+            end: 30,
+            offset: -22,
+        }]);
+        let h = f.then(&g);
+        for x in -5..40 {
+            assert_eq!(h.apply(x), g.apply(f.apply(x)), "at {x}");
+        }
+    }
+
+    #[test]
+    fn test_5_seed_to_location() {
+        let (seeds, x_to_y_maps) = parse_input(input()).unwrap();
+        let function = seed_to_location(&x_to_y_maps);
+        let locations: Vec<_> = seeds.iter().map(|&seed| function.apply(seed)).collect();
+        assert_eq!(locations, vec![82, 43, 86, 35]);
+        for seed in 0..110 {
+            let location = x_to_y_maps
+                .iter()
+                .fold(seed, |number, maps| maps.piecewise().apply(number));
+            assert_eq!(function.apply(seed), location, "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn test_5_truncated() {
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98";
+        let error = day5a(input).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 6));
+        assert_eq!(error.expected, "a range length");
+    }
+
+    #[test]
+    fn test_5_seeds() {
+        let maps = "\n\nx map:\n1 2 3";
+        let error = day5a(&format!("seeds: {maps}")).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 8));
+        assert_eq!(error.expected, "a seed");
+
+        // part a reads any seeds, part b needs pairs with positive lengths
+        let input = format!("seeds: 79 14 55{maps}");
+        assert_eq!(day5a(&input), Ok(14));
+        let error = day5b(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 16));
+        assert_eq!(error.expected, "a seed range length");
+        let parsed = Day05::parse(&input).unwrap();
+        assert_eq!(Day05::part_b(&parsed), Answer::None);
+
+        let input = format!("seeds: 79 0{maps}");
+        assert_eq!(day5a(&input), Ok(0));
+        let error = day5b(&input).unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (11, "0"));
+        assert_eq!(error.expected, "a positive seed range length");
+    }
+}
@@ -0,0 +1,175 @@
+use common::parse::{Context, ParseError};
+use common::solution::{Answer, Solution};
+
+/// A race's time and the record distance to beat.
+type Race = (u128, u128);
+
+fn parse_lines(input: &str) -> Result<(&str, &str), ParseError> {
+    let ctx = Context::new(input);
+    let mut lines = input.lines();
+    let time = ctx.strip_prefix(ctx.next(&mut lines, input, "a time line")?, "Time:")?;
+    let distance = ctx.strip_prefix(ctx.next(&mut lines, time, "a distance line")?, "Distance:")?;
+    Ok((time, distance))
+}
+
+fn parse_input_5a(input: &str) -> Result<Vec<Race>, ParseError> {
+    let ctx = Context::new(input);
+    let (time, distance) = parse_lines(input)?;
+    let times = time
+        .split_whitespace()
+        .map(|x| ctx.number(x))
+        .collect::<Result<Vec<_>, _>>()?;
+    let distances = distance
+        .split_whitespace()
+        .map(|x| ctx.number(x))
+        .collect::<Result<Vec<_>, _>>()?;
+    if distances.len() != times.len() {
+        return Err(ctx.error(distance, format!("{} distances", times.len())));
+    }
+    Ok(times.into_iter().zip(distances).collect::<Vec<_>>())
+}
+
+fn parse_input_5b(input: &str) -> Result<Race, ParseError> {
+    let ctx = Context::new(input);
+    let (time, dist) = parse_lines(input)?;
+    let concatenated = |line: &str| {
+        line.split_whitespace()
+            .fold("".to_string(), |a, b| a + b)
+            .parse::<u128>()
+            .map_err(|_| ctx.error(line, "a number"))
+    };
+    Ok((concatenated(time)?, concatenated(dist)?))
+}
+
+/// Counts the hold times `h` that beat the record, `h * (time - h) > distance`.
+/// They lie strictly between the roots `(time ± sqrt(time² - 4 distance)) / 2`,
+/// so the first one is found from the integer square root of the
+/// discriminant and the count follows by symmetry around `time / 2`. Times
+/// too long to square are searched for the first winning hold time instead.
+pub fn ways_to_win((time, distance): Race) -> u128 {
+    // a product too large for a u128 beats any distance
+    let beats = |h: u128| h.checked_mul(time - h).is_none_or(|d| d > distance);
+    let half = time / 2;
+    if !beats(half) {
+        return 0;
+    }
+    let first = match time.checked_mul(time) {
+        Some(square) => {
+            // beating the distance at half time puts it below square / 4
+            let discriminant = square - 4 * distance;
+            // within one of the lower root, corrected with exact comparisons
+            let mut first = (time - discriminant.isqrt()) / 2;
+            while !beats(first) {
+                first += 1;
+            }
+            while first > 0 && beats(first - 1) {
+                first -= 1;
+            }
+            first
+        }
+        None => {
+            let (mut low, mut high) = (0, half);
+            while low < high {
+                let mid = low + (high - low) / 2;
+                if beats(mid) {
+                    high = mid;
+                } else {
+                    low = mid + 1;
+                }
+            }
+            low
+        }
+    };
+    time - 2 * first + 1
+}
+
+fn product_of_ways_to_win(races: &[Race]) -> u128 {
+    races.iter().map(|&race| ways_to_win(race)).product()
+}
+
+pub fn day6a(input: &str) -> Result<u128, ParseError> {
+    Ok(product_of_ways_to_win(&parse_input_5a(input)?))
+}
+
+pub fn day6b(input: &str) -> Result<u128, ParseError> {
+    Ok(ways_to_win(parse_input_5b(input)?))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+
+    type Parsed<'a> = (Vec<Race>, Race);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok((parse_input_5a(input)?, parse_input_5b(input)?))
+    }
+
+    fn part_a((races, _): &Self::Parsed<'_>) -> Answer {
+        product_of_ways_to_win(races).into()
+    }
+
+    fn part_b((_, race): &Self::Parsed<'_>) -> Answer {
+        ways_to_win(*race).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn input() -> &'static str {
+        "Time:      7  15   30
+Distance:  9  40  200"
+    }
+
+    #[test]
+    fn test_6a() {
+        let input = input();
+        assert_eq!(day6a(input), Ok(288));
+    }
+
+    #[test]
+    fn test_6b() {
+        let input = input();
+        assert_eq!(day6b(input), Ok(71503));
+    }
+
+    #[test]
+    fn test_6_ways_to_win() {
+        let count = |time: u128, distance: u128| {
+            (0..=time).filter(|h| h * (time - h) > distance).count() as u128
+        };
+        for time in 0..60 {
+            for distance in 0..time * time / 4 + 3 {
+                assert_eq!(ways_to_win((time, distance)), count(time, distance));
+            }
+        }
+        assert_eq!(ways_to_win((10, 25)), 0);
+        assert_eq!(ways_to_win((10, u128::MAX)), 0);
+
+        let time = u128::from(u64::MAX);
+        let half = time / 2;
+        let distance = half * (time - half) - 1;
+        assert_eq!(ways_to_win((time, distance)), 2);
+        assert_eq!(ways_to_win((time, 0)), time - 1);
+
+        let input = "Time: 1844674407 3709551615\nDistance: 1 0";
+        assert_eq!(day6b(input), Ok(18446744073709551614));
+        assert_eq!(
+            day6b("Time: 1844674407 3709551616\nDistance: 1 0"),
+            Ok(18446744073709551615)
+        );
+
+        // times whose square does not fit a u128
+        assert_eq!(ways_to_win((1 << 70, 5)), (1 << 70) - 1);
+        assert_eq!(ways_to_win((u128::MAX, u128::MAX)), u128::MAX - 3);
+        let time = 3u128 << 63;
+        let half = time / 2;
+        assert_eq!(ways_to_win((time, half * half - 1)), 1);
+        assert_eq!(ways_to_win((time, half * half - 4)), 3);
+        assert_eq!(ways_to_win((time + 1, half * (half + 1) - 1)), 2);
+        assert!(day6b("Time: 340282366920938463463374607431768211456\nDistance: 1").is_err());
+    }
+}
@@ -0,0 +1,167 @@
+use common::parse::ParseError;
+use common::solution::{Answer, Solution};
+
+pub use crate::rules::{Category, Hand, Rules, Standing, TieBreak};
+
+mod rules;
+
+/// The standings from the strongest hand down, one per line, with what the
+/// wildcards became and the winnings of each hand.
+pub fn standings_table(standings: &[Standing]) -> String {
+    let width = standings
+        .iter()
+        .map(|standing| standing.hand.cards.chars().count())
+        .fold(4, usize::max);
+    let mut table = format!(
+        "{:>5} {:<width$} {:<width$} {:<15} {:>6} {:>10}\n",
+        "rank", "hand", "as", "category", "bid", "winnings"
+    );
+    for standing in standings.iter().rev() {
+        table += &format!(
+            "{:>5} {:<width$} {:<width$} {:<15} {:>6} {:>10}\n",
+            standing.rank,
+            standing.hand.cards,
+            standing.substituted,
+            standing.category,
+            standing.hand.bid,
+            standing.winnings
+        );
+    }
+    table
+}
+
+pub fn day07a(input: &str) -> Result<u64, ParseError> {
+    let rules = Rules::standard();
+    Ok(rules.total_winnings(&rules.parse_hands(input)?))
+}
+
+pub fn day07b(input: &str) -> Result<u64, ParseError> {
+    let rules = Rules::jokers();
+    Ok(rules.total_winnings(&rules.parse_hands(input)?))
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+
+    type Parsed<'a> = Vec<Hand<'a>>;
+
+    /// Both parts deal the same cards, so the hands are checked once.
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Rules::standard().parse_hands(input)
+    }
+
+    fn part_a(hands: &Self::Parsed<'_>) -> Answer {
+        Rules::standard().total_winnings(hands).into()
+    }
+
+    fn part_b(hands: &Self::Parsed<'_>) -> Answer {
+        Rules::jokers().total_winnings(hands).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn input() -> &'static str {
+        "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483"
+    }
+
+    #[test]
+    fn test_7a() {
+        let input = input();
+        assert_eq!(day07a(input), Ok(6440));
+    }
+
+    #[test]
+    fn test_7b() {
+        let input = input();
+        assert_eq!(day07b(input), Ok(5905));
+    }
+
+    #[test]
+    fn test_7_invalid_card() {
+        let error = day07a("32T3K 765\nT55X5 684").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.token, "X");
+        let error = day07a("32T3 765").unwrap_err();
+        assert_eq!(error.token, "32T3");
+    }
+
+    #[test]
+    fn test_7_categories() {
+        let (standard, jokers) = (Rules::standard(), Rules::jokers());
+        assert_eq!(standard.category("KTJJT"), Category::TwoPair);
+        assert_eq!(jokers.category("KTJJT"), Category::FourOfAKind);
+        assert_eq!(jokers.category("JJJJJ"), Category::FiveOfAKind);
+        assert_eq!(jokers.substitute("JJJJJ"), "AAAAA");
+        assert_eq!(jokers.substitute("2J3J4"), "24344");
+        assert_eq!(jokers.category("2J3J4"), Category::ThreeOfAKind);
+        assert_eq!(jokers.category("33J44"), Category::FullHouse);
+        assert_eq!(standard.category("23456"), Category::HighCard);
+    }
+
+    #[test]
+    fn test_7_standings() {
+        let rules = Rules::jokers();
+        let hands = rules.parse_hands(input()).unwrap();
+        let standings = rules.standings(&hands);
+        let total: u64 = standings.iter().map(|standing| standing.winnings).sum();
+        assert_eq!(total, rules.total_winnings(&hands));
+        let strongest = &standings[4];
+        assert_eq!(strongest.hand.cards, "KTJJT");
+        assert_eq!(strongest.substituted, "KTTTT");
+        assert_eq!(strongest.category, Category::FourOfAKind);
+        assert_eq!((strongest.rank, strongest.winnings), (5, 1100));
+
+        assert_eq!(
+            standings_table(&standings),
+            " rank hand  as    category           bid   winnings
+    5 KTJJT KTTTT four of a kind     220       1100
+    4 QQQJA QQQQA four of a kind     483       1932
+    3 T55J5 T5555 four of a kind     684       2052
+    2 KK677 KK677 two pair            28         56
+    1 32T3K 32T3K one pair           765        765
+"
+        );
+    }
+
+    #[test]
+    fn test_7_house_rules() {
+        // deuces are wild too
+        let deuces = Rules {
+            order: "J23456789TQKA".to_string(),
+            wildcards: "J2".to_string(),
+            ..Rules::standard()
+        };
+        assert_eq!(deuces.category("2J3K4"), Category::ThreeOfAKind);
+        assert_eq!(deuces.category("22JJA"), Category::FiveOfAKind);
+        let hands = deuces.parse_hands(input()).unwrap();
+        let sorted: Vec<&str> = deuces.sort(&hands).iter().map(|hand| hand.cards).collect();
+        assert_eq!(sorted, vec!["KK677", "32T3K", "T55J5", "QQQJA", "KTJJT"]);
+
+        let poker = Rules {
+            tie_break: TieBreak::Grouped,
+            ..Rules::standard()
+        };
+        let hands = poker.parse_hands("KKA22 1\n22AAK 2\nAKQ23 3").unwrap();
+        let sorted: Vec<&str> = poker.sort(&hands).iter().map(|hand| hand.cards).collect();
+        assert_eq!(sorted, vec!["AKQ23", "KKA22", "22AAK"]);
+        assert_eq!(poker.total_winnings(&hands), 3 + 2 + 2 * 3);
+
+        let six = Rules {
+            hand_size: Some(6),
+            ..Rules::standard()
+        };
+        let hands = six.parse_hands("333KKK 1\n22AAAA 1").unwrap();
+        assert_eq!(six.category(hands[0].cards), Category::FullHouse);
+        assert_eq!(six.category(hands[1].cards), Category::FourOfAKind);
+        assert!(six.parse_hands(input()).is_err());
+    }
+}
@@ -0,0 +1,232 @@
+use common::parse::{Context, ParseError};
+use std::cmp::Reverse;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Category {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl Category {
+    /// The category of a hand whose groups of equal cards have `counts`
+    /// cards, the largest first. Hands of other sizes than five are
+    /// categorised by their two largest groups.
+    fn new(counts: &[usize]) -> Self {
+        match counts {
+            [n, ..] if *n >= 5 => Category::FiveOfAKind,
+            [4, ..] => Category::FourOfAKind,
+            [3, n, ..] if *n >= 2 => Category::FullHouse,
+            [3, ..] => Category::ThreeOfAKind,
+            [2, 2, ..] => Category::TwoPair,
+            [2, ..] => Category::OnePair,
+            _ => Category::HighCard,
+        }
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Category::HighCard => "high card",
+            Category::OnePair => "one pair",
+            Category::TwoPair => "two pair",
+            Category::ThreeOfAKind => "three of a kind",
+            Category::FullHouse => "full house",
+            Category::FourOfAKind => "four of a kind",
+            Category::FiveOfAKind => "five of a kind",
+        };
+        f.pad(name)
+    }
+}
+
+/// How hands of the same category are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieBreak {
+    /// Card by card in the order they were dealt, wildcards at their own
+    /// strength.
+    Dealt,
+    /// Card by card from the largest group down and the strongest card first
+    /// within groups of the same size, as in poker. Wildcards count as the
+    /// card they stand in for.
+    Grouped,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hand<'a> {
+    pub cards: &'a str,
+    pub bid: u32,
+}
+
+/// A hand's place in the game, and what its wildcards became to get there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standing<'a> {
+    pub hand: Hand<'a>,
+    /// The hand with its wildcards replaced, see [`Rules::substitute`].
+    pub substituted: String,
+    pub category: Category,
+    /// 1 for the weakest hand.
+    pub rank: u64,
+    /// The bid times the rank.
+    pub winnings: u64,
+}
+
+/// A variant of Camel Cards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    /// The cards from the weakest to the strongest.
+    pub order: String,
+    /// The cards that stand in for whichever card makes the best hand.
+    pub wildcards: String,
+    pub tie_break: TieBreak,
+    /// The number of cards in a hand, if it is fixed.
+    pub hand_size: Option<usize>,
+}
+
+impl Rules {
+    /// The rules of part a.
+    pub fn standard() -> Self {
+        Rules {
+            order: "23456789TJQKA".to_string(),
+            wildcards: String::new(),
+            tie_break: TieBreak::Dealt,
+            hand_size: Some(5),
+        }
+    }
+
+    /// The rules of part b, where jacks are jokers and the weakest card.
+    pub fn jokers() -> Self {
+        Rules {
+            order: "J23456789TQKA".to_string(),
+            wildcards: "J".to_string(),
+            ..Rules::standard()
+        }
+    }
+
+    fn strength(&self, card: char) -> Option<usize> {
+        self.order.chars().position(|c| c == card)
+    }
+
+    pub fn is_wild(&self, card: char) -> bool {
+        self.wildcards.contains(card)
+    }
+
+    /// The sizes of the groups of equal cards, the largest first, each with
+    /// its card.
+    fn groups(&self, cards: &str) -> Vec<(usize, char)> {
+        let mut groups: Vec<(usize, char)> = Vec::new();
+        for card in cards.chars() {
+            match groups.iter_mut().find(|(_, c)| *c == card) {
+                Some((count, _)) => *count += 1,
+                None => groups.push((1, card)),
+            }
+        }
+        groups.sort_by_key(|&(count, card)| Reverse((count, self.strength(card))));
+        groups
+    }
+
+    /// Replaces the wildcards of `cards` with the card that makes the best
+    /// hand: the most common other card, the strongest of those if several
+    /// are, or the strongest card if all of them are wild.
+    pub fn substitute(&self, cards: &str) -> String {
+        let plain: String = cards.chars().filter(|&c| !self.is_wild(c)).collect();
+        let best = match self.groups(&plain).first() {
+            Some(&(_, card)) => Some(card),
+            None => self.order.chars().rev().find(|&c| !self.is_wild(c)),
+        };
+        match best {
+            Some(best) => cards
+                .chars()
+                .map(|c| if self.is_wild(c) { best } else { c })
+                .collect(),
+            None => cards.to_string(),
+        }
+    }
+
+    pub fn category(&self, cards: &str) -> Category {
+        let counts: Vec<usize> = self
+            .groups(&self.substitute(cards))
+            .iter()
+            .map(|&(count, _)| count)
+            .collect();
+        Category::new(&counts)
+    }
+
+    /// What hands are sorted by, from the weakest to the strongest.
+    fn key(&self, cards: &str) -> (Category, Vec<Option<usize>>) {
+        let strengths = match self.tie_break {
+            TieBreak::Dealt => cards.chars().map(|c| self.strength(c)).collect(),
+            TieBreak::Grouped => self
+                .groups(&self.substitute(cards))
+                .iter()
+                .flat_map(|&(count, card)| vec![self.strength(card); count])
+                .collect(),
+        };
+        (self.category(cards), strengths)
+    }
+
+    /// The hands ordered from the weakest to the strongest.
+    pub fn sort<'a>(&self, hands: &[Hand<'a>]) -> Vec<Hand<'a>> {
+        let mut hands = hands.to_vec();
+        hands.sort_by_cached_key(|hand| self.key(hand.cards));
+        hands
+    }
+
+    /// The standing of each hand, from the weakest to the strongest.
+    pub fn standings<'a>(&self, hands: &[Hand<'a>]) -> Vec<Standing<'a>> {
+        self.sort(hands)
+            .into_iter()
+            .zip(1..)
+            .map(|(hand, rank)| Standing {
+                hand,
+                substituted: self.substitute(hand.cards),
+                category: self.category(hand.cards),
+                rank,
+                winnings: u64::from(hand.bid) * rank,
+            })
+            .collect()
+    }
+
+    /// Sums the bid of each hand times its rank, the weakest hand being
+    /// ranked 1.
+    pub fn total_winnings(&self, hands: &[Hand]) -> u64 {
+        self.sort(hands)
+            .iter()
+            .zip(1..)
+            .map(|(hand, rank)| u64::from(hand.bid) * rank)
+            .sum()
+    }
+
+    /// Parses one hand and its bid per line, checking the cards against
+    /// these rules.
+    pub fn parse_hands<'a>(&self, input: &'a str) -> Result<Vec<Hand<'a>>, ParseError> {
+        let ctx = Context::new(input);
+        input
+            .lines()
+            .map(|line| {
+                let (cards, bid) = ctx.split_once(line, " ")?;
+                if let Some((i, c)) = cards
+                    .char_indices()
+                    .find(|&(_, c)| self.strength(c).is_none())
+                {
+                    return Err(ctx.error(&cards[i..i + c.len_utf8()], "a card"));
+                }
+                match self.hand_size {
+                    Some(size) if cards.chars().count() != size => {
+                        return Err(ctx.error(cards, format!("a hand of {size} cards")))
+                    }
+                    _ => {}
+                }
+                Ok(Hand {
+                    cards,
+                    bid: ctx.number(bid)?,
+                })
+            })
+            .collect()
+    }
+}
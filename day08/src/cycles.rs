@@ -0,0 +1,87 @@
+use num::integer::Integer;
+
+/// The steps at which a walker is on an end node. A walker's next move only
+/// depends on its node and the instruction it is at, so once such a state
+/// repeats the walk repeats forever.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Walk {
+    /// The steps taken before the walker enters its cycle.
+    pub tail: u64,
+    /// The steps taken going once around the cycle.
+    pub cycle: u64,
+    /// The steps below `tail + cycle` at which the walker is on an end node,
+    /// in order. Those from `tail` on recur every `cycle` steps.
+    pub hits: Vec<u64>,
+}
+
+impl Walk {
+    pub fn is_hit(&self, step: u64) -> bool {
+        let step = match step.checked_sub(self.tail) {
+            Some(into_cycle) => self.tail + into_cycle % self.cycle,
+            None => step,
+        };
+        self.hits.binary_search(&step).is_ok()
+    }
+
+    /// The hits that recur, as remainders modulo the cycle length.
+    fn residues(&self) -> impl Iterator<Item = u64> + '_ {
+        self.hits
+            .iter()
+            .filter(|&&hit| hit >= self.tail)
+            .map(|hit| hit % self.cycle)
+    }
+}
+
+/// Combines `x ≡ a (mod m)` and `x ≡ b (mod n)` into one congruence modulo
+/// `lcm(m, n)` with the Chinese Remainder Theorem, `None` if they have no
+/// common solution. The moduli need not be coprime.
+fn crt((a, m): (u128, u128), (b, n): (u128, u128)) -> Option<(u128, u128)> {
+    let (a, m, b, n) = (a as i128, m as i128, b as i128, n as i128);
+    let gcd = m.extended_gcd(&n);
+    if (b - a) % gcd.gcd != 0 {
+        return None;
+    }
+    let n_g = n / gcd.gcd;
+    // m * gcd.x ≡ gcd.gcd (mod n), so a + k * m ≡ b (mod n) for this k
+    let k =
+        ((b - a) / gcd.gcd).mod_floor(&n_g) as u128 * gcd.x.mod_floor(&n_g) as u128 % n_g as u128;
+    let lcm = m as u128 * n_g as u128;
+    Some(((a as u128 + k * m as u128) % lcm, lcm))
+}
+
+/// The first step at which every walker is on an end node at once, `None`
+/// if they never are.
+pub fn first_common_hit(walks: &[Walk]) -> Option<u128> {
+    let tail = walks.iter().map(|walk| walk.tail).max()?;
+    if let Some(step) = (0..tail).find(|&step| walks.iter().all(|walk| walk.is_hit(step))) {
+        return Some(u128::from(step));
+    }
+
+    // from here on every walker is in its cycle, so each step it is on an end
+    // node solves one of its congruences
+    let mut residues = vec![0];
+    let mut modulus = 1;
+    for walk in walks {
+        let cycle = u128::from(walk.cycle);
+        let mut combined: Vec<u128> = residues
+            .iter()
+            .flat_map(|&r| {
+                walk.residues()
+                    .filter_map(move |hit| crt((r, modulus), (u128::from(hit), cycle)))
+                    .map(|(r, _)| r)
+            })
+            .collect();
+        combined.sort_unstable();
+        combined.dedup();
+        residues = combined;
+        modulus = modulus.lcm(&cycle);
+    }
+    let tail = u128::from(tail);
+    residues
+        .into_iter()
+        .map(|r| match tail.checked_sub(r) {
+            Some(behind) if behind > 0 => r + behind.div_ceil(modulus) * modulus,
+            _ => r,
+        })
+        .min()
+}
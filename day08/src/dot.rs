@@ -0,0 +1,60 @@
+use std::collections::VecDeque;
+
+use crate::network::Network;
+
+/// The nodes reachable from `start`, itself included, in id order.
+fn reachable(network: &Network, start: u32) -> Vec<u32> {
+    let mut seen = vec![false; network.names.len()];
+    seen[start as usize] = true;
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        for next in network.next[node as usize] {
+            if !seen[next as usize] {
+                seen[next as usize] = true;
+                queue.push_back(next);
+            }
+        }
+    }
+    (0..network.names.len() as u32)
+        .filter(|&node| seen[node as usize])
+        .collect()
+}
+
+/// The network as a Graphviz DOT graph, with an edge per instruction labelled
+/// `L` or `R`, or `L/R` where both lead to the same node. Start nodes,
+/// ending in `A`, are filled green and end nodes, ending in `Z`, red. With a
+/// `start` only the nodes reachable from it are drawn.
+pub fn to_dot(network: &Network, start: Option<u32>) -> String {
+    let nodes = match start {
+        Some(start) => reachable(network, start),
+        None => (0..network.names.len() as u32).collect(),
+    };
+    let mut dot = "digraph network {\n".to_string();
+    for &node in &nodes {
+        let name = network.name(node);
+        let color = match name.chars().last() {
+            Some('A') => "palegreen",
+            Some('Z') => "lightcoral",
+            _ => continue,
+        };
+        dot += &format!("    \"{name}\" [style=filled, fillcolor={color}];\n");
+    }
+    for &node in &nodes {
+        let name = network.name(node);
+        let [left, right] = network.next[node as usize];
+        if left == right {
+            dot += &format!(
+                "    \"{name}\" -> \"{}\" [label=\"L/R\"];\n",
+                network.name(left)
+            );
+            continue;
+        }
+        for (next, label) in [(left, "L"), (right, "R")] {
+            dot += &format!(
+                "    \"{name}\" -> \"{}\" [label=\"{label}\"];\n",
+                network.name(next)
+            );
+        }
+    }
+    dot + "}\n"
+}
@@ -0,0 +1,233 @@
+use common::parse::ParseError;
+use common::solution::{Answer, Solution};
+
+pub use crate::cycles::{first_common_hit, Walk};
+pub use crate::dot::to_dot;
+pub use crate::network::{Network, NodeSet};
+
+mod cycles;
+mod dot;
+mod network;
+
+/// The steps until every walker from `starts` is on one of `ends` at once.
+fn steps(network: &Network, starts: &[u32], ends: &[u32]) -> Option<u128> {
+    let ends = network.node_set(ends);
+    let walks: Vec<Walk> = starts
+        .iter()
+        .map(|&start| network.analyse(start, &ends))
+        .collect();
+    first_common_hit(&walks)
+}
+
+fn human_steps(network: &Network) -> Option<u128> {
+    let start = network.id("AAA")?;
+    steps(
+        network,
+        &[start],
+        &network.nodes_where(|node| node == "ZZZ"),
+    )
+}
+
+fn ghost_steps(network: &Network) -> Option<u128> {
+    let starts = network.nodes_where(|node| node.ends_with('A'));
+    steps(
+        network,
+        &starts,
+        &network.nodes_where(|node| node.ends_with('Z')),
+    )
+}
+
+pub fn day8a(input: &str) -> Result<Option<u128>, ParseError> {
+    Ok(human_steps(&Network::parse(input)?))
+}
+
+pub fn day8b(input: &str) -> Result<Option<u128>, ParseError> {
+    Ok(ghost_steps(&Network::parse(input)?))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+
+    type Parsed<'a> = Network<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Network::parse(input)
+    }
+
+    fn part_a(network: &Self::Parsed<'_>) -> Answer {
+        human_steps(network).into()
+    }
+
+    fn part_b(network: &Self::Parsed<'_>) -> Answer {
+        ghost_steps(network).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn input1() -> &'static str {
+        "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)"
+    }
+
+    fn input2() -> &'static str {
+        "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"
+    }
+
+    fn input3() -> &'static str {
+        "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)"
+    }
+
+    #[test]
+    fn test_8a() {
+        let input1 = input1();
+        assert_eq!(day8a(input1), Ok(Some(2)));
+
+        let input2 = input2();
+        assert_eq!(day8a(input2), Ok(Some(6)));
+    }
+
+    #[test]
+    fn test_8b() {
+        let input3 = input3();
+        assert_eq!(day8b(input3), Ok(Some(6)));
+    }
+
+    #[test]
+    fn test_8_analyse() {
+        let network = Network::parse(input3()).unwrap();
+        let ends = network.node_set(&network.nodes_where(|node| node.ends_with('Z')));
+        let analyse = |start| network.analyse(network.id(start).unwrap(), &ends);
+        let walk = analyse("22A");
+        assert_eq!(
+            walk,
+            Walk {
+                tail: 1,
+                cycle: 6,
+                hits: vec![3, 6]
+            }
+        );
+        assert!(walk.is_hit(9) && walk.is_hit(12) && !walk.is_hit(10));
+        let walk = analyse("11A");
+        assert_eq!((walk.tail, walk.cycle, walk.hits), (1, 2, vec![2]));
+        assert!(!analyse("XXX").is_hit(4));
+    }
+
+    #[test]
+    fn test_8_network() {
+        let network = Network::parse(input3()).unwrap();
+        assert_eq!(network.instructions, vec![0, 1]);
+        assert_eq!(network.id("22A"), Some(3));
+        assert_eq!(network.name(3), "22A");
+        assert_eq!(network.next[0], [1, 7]);
+        assert_eq!(network.nodes_where(|node| node.ends_with('Z')), vec![2, 6]);
+        let ends = network.node_set(&[2, 6]);
+        assert!(ends.contains(6) && !ends.contains(7));
+
+        let starts = network.nodes_where(|node| node.ends_with('A'));
+        assert_eq!(network.simulate(&starts, &ends, 100), Some(6));
+        assert_eq!(network.simulate(&starts, &ends, 5), None);
+        assert_eq!(network.simulate(&[7], &ends, 1000), None);
+
+        let error = Network::parse("Lé\n\nAAA = (AAA, AAA)").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token),
+            (1, 2, "é".to_string())
+        );
+        let error = Network::parse("L\n\nAAA = (AAA, BBB)").unwrap_err();
+        assert_eq!(error.token, "BBB");
+        assert!(Network::parse("L\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)").is_err());
+    }
+
+    #[test]
+    fn test_8_to_dot() {
+        let network = Network::parse(input2()).unwrap();
+        assert_eq!(
+            to_dot(&network, None),
+            r#"digraph network {
+    "AAA" [style=filled, fillcolor=palegreen];
+    "ZZZ" [style=filled, fillcolor=lightcoral];
+    "AAA" -> "BBB" [label="L/R"];
+    "BBB" -> "AAA" [label="L"];
+    "BBB" -> "ZZZ" [label="R"];
+    "ZZZ" -> "ZZZ" [label="L/R"];
+}
+"#
+        );
+
+        let network = Network::parse(input3()).unwrap();
+        let dot = to_dot(&network, network.id("22A"));
+        assert!(dot.contains("\"22A\" [style=filled, fillcolor=palegreen];"));
+        assert!(dot.contains("\"22Z\" -> \"22B\" [label=\"L/R\"];"));
+        assert!(!dot.contains("11"));
+        assert!(dot.contains("\"22A\" -> \"XXX\" [label=\"R\"];"));
+        assert_eq!(to_dot(&network, network.id("XXX")).lines().count(), 3);
+    }
+
+    #[test]
+    fn test_8_first_common_hit() {
+        let walk = |tail, cycle, hits: &[u64]| Walk {
+            tail,
+            cycle,
+            hits: hits.to_vec(),
+        };
+        // an end node in the tail only
+        assert_eq!(first_common_hit(&[walk(5, 3, &[2])]), Some(2));
+        assert_eq!(
+            first_common_hit(&[walk(5, 3, &[2]), walk(0, 4, &[1])]),
+            None
+        );
+        assert_eq!(
+            first_common_hit(&[walk(5, 3, &[2, 6]), walk(0, 4, &[1])]),
+            Some(9)
+        );
+        // offsets that are not multiples of the cycle
+        assert_eq!(
+            first_common_hit(&[walk(3, 4, &[4]), walk(1, 6, &[2])]),
+            Some(8)
+        );
+        // several end nodes per cycle, and a tail longer than the cycles
+        assert_eq!(
+            first_common_hit(&[walk(10, 5, &[11, 13]), walk(2, 7, &[5])]),
+            Some(26)
+        );
+        // cycles of even length that never line up on odd and even steps
+        assert_eq!(
+            first_common_hit(&[walk(0, 2, &[0]), walk(0, 4, &[1])]),
+            None
+        );
+        assert_eq!(first_common_hit(&[]), None);
+
+        let brute = |walks: &[Walk]| (0..10_000).find(|&s| walks.iter().all(|w| w.is_hit(s)));
+        for (a, b) in [(3, 4), (6, 10), (9, 12), (5, 5)] {
+            for offset in 0..a {
+                let walks = [walk(2, a, &[2 + offset]), walk(7, b, &[7, 8])];
+                assert_eq!(first_common_hit(&walks), brute(&walks).map(u128::from));
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use common::parse::{Context, ParseError};
+use std::collections::HashMap;
+
+use crate::cycles::Walk;
+
+/// A set of nodes, one bit per node id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeSet {
+    bits: Vec<u64>,
+}
+
+impl NodeSet {
+    pub fn contains(&self, node: u32) -> bool {
+        self.bits[node as usize / 64] & 1 << (node % 64) != 0
+    }
+}
+
+/// The network with its nodes numbered in the order they are listed, each
+/// with the ids of its left and right successors.
+#[derive(Debug, Clone)]
+pub struct Network<'a> {
+    /// The instructions, 0 for left and 1 for right.
+    pub instructions: Vec<u8>,
+    pub names: Vec<&'a str>,
+    pub next: Vec<[u32; 2]>,
+    ids: HashMap<&'a str, u32>,
+}
+
+impl<'a> Network<'a> {
+    pub fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let ctx = Context::new(input);
+        let (instructions, network_raw) = ctx.split_once(input, "\n\n")?;
+        if let Some((i, c)) = instructions
+            .char_indices()
+            .find(|&(_, c)| c != 'L' && c != 'R')
+        {
+            return Err(ctx.error(&instructions[i..i + c.len_utf8()], "'L' or 'R'"));
+        }
+        if instructions.is_empty() {
+            return Err(ctx.missing(instructions, "'L' or 'R'"));
+        }
+        let mut names = Vec::new();
+        let mut children = Vec::new();
+        let mut ids = HashMap::new();
+        for line in network_raw.lines() {
+            let (node, pair) = ctx.split_once(line, " = ")?;
+            let pair = ctx.strip_suffix(ctx.strip_prefix(pair, "(")?, ")")?;
+            if ids.insert(node, names.len() as u32).is_some() {
+                return Err(ctx.error(node, "a node not listed before"));
+            }
+            names.push(node);
+            children.push(ctx.split_once(pair, ", ")?);
+        }
+        let id = |child| {
+            ids.get(child)
+                .copied()
+                .ok_or_else(|| ctx.error(child, "a node of the network"))
+        };
+        let next = children
+            .into_iter()
+            .map(|(left, right)| Ok([id(left)?, id(right)?]))
+            .collect::<Result<_, ParseError>>()?;
+        Ok(Network {
+            instructions: instructions.bytes().map(|b| u8::from(b == b'R')).collect(),
+            names,
+            next,
+            ids,
+        })
+    }
+
+    pub fn id(&self, name: &str) -> Option<u32> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: u32) -> &'a str {
+        self.names[id as usize]
+    }
+
+    /// The nodes whose name matches `predicate`, in id order.
+    pub fn nodes_where(&self, predicate: impl Fn(&str) -> bool) -> Vec<u32> {
+        (0..self.names.len() as u32)
+            .filter(|&id| predicate(self.name(id)))
+            .collect()
+    }
+
+    pub fn node_set(&self, nodes: &[u32]) -> NodeSet {
+        let mut bits = vec![0; self.names.len().div_ceil(64)];
+        for &node in nodes {
+            bits[node as usize / 64] |= 1 << (node % 64);
+        }
+        NodeSet { bits }
+    }
+
+    /// Where a walker on `node` goes at step `step`.
+    pub fn step(&self, node: u32, step: u64) -> u32 {
+        let side = self.instructions[(step % self.instructions.len() as u64) as usize];
+        self.next[node as usize][side as usize]
+    }
+
+    /// Follows the instructions from `start` until a state, a node and an
+    /// instruction, repeats, noting when the walker is on one of `ends`.
+    pub fn analyse(&self, start: u32, ends: &NodeSet) -> Walk {
+        let period = self.instructions.len();
+        // the step each state was first seen at, by node and then instruction
+        let mut seen = vec![u64::MAX; self.names.len() * period];
+        let mut hits = Vec::new();
+        let mut node = start;
+        for step in 0.. {
+            let state = node as usize * period + (step % period as u64) as usize;
+            if seen[state] != u64::MAX {
+                return Walk {
+                    tail: seen[state],
+                    cycle: step - seen[state],
+                    hits,
+                };
+            }
+            seen[state] = step;
+            if ends.contains(node) {
+                hits.push(step);
+            }
+            node = self.step(node, step);
+        }
+        unreachable!("the states are finite")
+    }
+
+    /// Moves walkers from every node of `starts` in lockstep until they are
+    /// all on `ends` at once, giving up after `limit` steps.
+    pub fn simulate(&self, starts: &[u32], ends: &NodeSet, limit: u64) -> Option<u64> {
+        let mut nodes = starts.to_vec();
+        for step in 0..=limit {
+            if nodes.iter().all(|&node| ends.contains(node)) {
+                return Some(step);
+            }
+            for node in &mut nodes {
+                *node = self.step(*node, step);
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,192 @@
+use common::parse::{Context, ParseError};
+use common::solution::{Answer, Solution};
+
+/// A sequence of values, fitted by the polynomial of lowest degree through
+/// all of them. Its difference table is built once, and only its first
+/// column is kept: the Newton forward differences, from which the polynomial
+/// gives the value at any index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sequence {
+    len: usize,
+    /// The first value of each row of the difference table, down to the last
+    /// row that is not all zeros.
+    differences: Vec<i128>,
+}
+
+impl Sequence {
+    /// `None` if a difference overflows an `i128`.
+    pub fn new(values: &[i64]) -> Option<Self> {
+        let mut row: Vec<i128> = values.iter().map(|&value| i128::from(value)).collect();
+        let mut differences = Vec::new();
+        while row.iter().any(|&value| value != 0) {
+            differences.push(row[0]);
+            row = row
+                .windows(2)
+                .map(|pair| pair[1].checked_sub(pair[0]))
+                .collect::<Option<_>>()?;
+        }
+        Some(Sequence {
+            len: values.len(),
+            differences,
+        })
+    }
+
+    /// The coefficients `Δᵏy₀` of the polynomial in Newton's forward
+    /// difference form, `p(x) = Σ Δᵏy₀ · C(x, k)` with `x` the index.
+    pub fn newton_coefficients(&self) -> &[i128] {
+        &self.differences
+    }
+
+    /// The degree of the polynomial, `None` if all values are zero.
+    pub fn degree(&self) -> Option<usize> {
+        self.differences.iter().rposition(|&d| d != 0)
+    }
+
+    /// The value at `index`, which may lie outside the sequence, `None` if it
+    /// overflows an `i128`.
+    pub fn value_at(&self, index: i128) -> Option<i128> {
+        // C(index, k), the binomial coefficient extended to any integer index
+        let mut binomial: i128 = 1;
+        let mut value: i128 = 0;
+        for (k, &difference) in self.differences.iter().enumerate() {
+            value = value.checked_add(difference.checked_mul(binomial)?)?;
+            if k + 1 == self.differences.len() {
+                // the next coefficient is not needed and may not fit
+                break;
+            }
+            // the product of k + 1 consecutive integers is divisible by (k + 1)!
+            binomial = binomial.checked_mul(index - k as i128)? / (k as i128 + 1);
+        }
+        Some(value)
+    }
+
+    /// The value `steps` after the last one.
+    pub fn forward(&self, steps: u64) -> Option<i128> {
+        self.value_at(self.len as i128 - 1 + i128::from(steps))
+    }
+
+    /// The value `steps` before the first one.
+    pub fn backward(&self, steps: u64) -> Option<i128> {
+        self.value_at(-i128::from(steps))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Sequence>, ParseError> {
+    let ctx = Context::new(input);
+    input
+        .lines()
+        .map(|line| {
+            let values = line
+                .split_whitespace()
+                .map(|num| ctx.number::<i64>(num))
+                .collect::<Result<Vec<i64>, _>>()?;
+            if values.is_empty() {
+                return Err(ctx.missing(line, "a number"));
+            }
+            Sequence::new(&values).ok_or_else(|| ctx.error(line, "differences within 128 bits"))
+        })
+        .collect()
+}
+
+/// Sums what `extrapolate` gives for each sequence, `None` on overflow.
+fn sum_of(sequences: &[Sequence], extrapolate: impl Fn(&Sequence) -> Option<i128>) -> Option<i128> {
+    sequences.iter().try_fold(0i128, |sum, sequence| {
+        sum.checked_add(extrapolate(sequence)?)
+    })
+}
+
+fn sum_of_next_values(sequences: &[Sequence]) -> Option<i128> {
+    sum_of(sequences, |sequence| sequence.forward(1))
+}
+
+fn sum_of_previous_values(sequences: &[Sequence]) -> Option<i128> {
+    sum_of(sequences, |sequence| sequence.backward(1))
+}
+
+pub fn day9a(input: &str) -> Result<Option<i128>, ParseError> {
+    Ok(sum_of_next_values(&parse_input(input)?))
+}
+
+pub fn day9b(input: &str) -> Result<Option<i128>, ParseError> {
+    Ok(sum_of_previous_values(&parse_input(input)?))
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+
+    type Parsed<'a> = Vec<Sequence>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a(sequences: &Self::Parsed<'_>) -> Answer {
+        sum_of_next_values(sequences).into()
+    }
+
+    fn part_b(sequences: &Self::Parsed<'_>) -> Answer {
+        sum_of_previous_values(sequences).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn input() -> &'static str {
+        "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45"
+    }
+
+    #[test]
+    fn test_9a() {
+        let input = input();
+        assert_eq!(day9a(input), Ok(Some(114)));
+    }
+    #[test]
+    fn test_9b() {
+        let input = input();
+        assert_eq!(day9b(input), Ok(Some(2)));
+    }
+
+    #[test]
+    fn test_9_sequence() {
+        let sequence = Sequence::new(&[10, 13, 16, 21, 30, 45]).unwrap();
+        assert_eq!(sequence.degree(), Some(3));
+        assert_eq!(sequence.newton_coefficients(), &[10, 3, 0, 2]);
+        assert_eq!(sequence.value_at(2), Some(16));
+        assert_eq!(sequence.forward(1), Some(68));
+        assert_eq!(sequence.backward(1), Some(5));
+        let forward: Vec<i128> = (0..4).map(|k| sequence.forward(k).unwrap()).collect();
+        assert_eq!(forward, vec![45, 68, 101, 146]);
+        let backward: Vec<i128> = (0..4).map(|k| sequence.backward(k).unwrap()).collect();
+        assert_eq!(backward, vec![10, 5, -4, -19]);
+
+        let squares = Sequence::new(&[0, 1, 4, 9]).unwrap();
+        assert_eq!(squares.degree(), Some(2));
+        assert_eq!(squares.forward(1_000_000), Some(1_000_003i128.pow(2)));
+        assert_eq!(squares.backward(7), Some(49));
+
+        let zeros = Sequence::new(&[0, 0, 0]).unwrap();
+        assert_eq!((zeros.degree(), zeros.forward(5)), (None, Some(0)));
+        assert_eq!(Sequence::new(&[7]).unwrap().backward(3), Some(7));
+    }
+
+    #[test]
+    fn test_9_overflow() {
+        let big = Sequence::new(&[i64::MIN, i64::MAX, i64::MIN]).unwrap();
+        assert_eq!(big.degree(), Some(2));
+        // the second difference is -2 * u64::MAX, more than an i64 holds
+        let expected = i128::from(i64::MIN) - 3 * i128::from(u64::MAX);
+        assert_eq!(big.forward(1), Some(expected));
+        assert_eq!(big.forward(u64::MAX), None);
+        let linear = Sequence::new(&[0, 1, 2]).unwrap();
+        assert_eq!(linear.forward(u64::MAX), Some(i128::from(u64::MAX) + 2));
+        let squares = Sequence::new(&[0, 1, 4, 9]).unwrap();
+        assert_eq!(squares.forward(1 << 50), Some(((1i128 << 50) + 3).pow(2)));
+        assert_eq!(day9a("1 x").unwrap_err().token, "x");
+    }
+}
@@ -0,0 +1,430 @@
+use common::geometry::Direction4;
+use common::grid::{Grid, Pos};
+use common::parse::{Context, ParseError};
+use common::solution::{Answer, Solution};
+
+#[derive(Debug, Clone)]
+pub enum Pipe {
+    NorthSouth(bool),
+    EastWest(bool),
+    NorthEast(bool),
+    NorthWest(bool),
+    SouthEast(bool),
+    SouthWest(bool),
+    Start(bool),
+}
+
+impl Pipe {
+    fn find_next_direction(&self, prev_direction: Direction4) -> Option<Direction4> {
+        match self {
+            Pipe::NorthSouth(_) => match prev_direction {
+                Direction4::North => Some(Direction4::North),
+                Direction4::South => Some(Direction4::South),
+                _ => None,
+            },
+            Pipe::EastWest(_) => match prev_direction {
+                Direction4::East => Some(Direction4::East),
+                Direction4::West => Some(Direction4::West),
+                _ => None,
+            },
+            Pipe::NorthEast(_) => match prev_direction {
+                Direction4::South => Some(Direction4::East),
+                Direction4::West => Some(Direction4::North),
+                _ => None,
+            },
+            Pipe::NorthWest(_) => match prev_direction {
+                Direction4::South => Some(Direction4::West),
+                Direction4::East => Some(Direction4::North),
+                _ => None,
+            },
+            Pipe::SouthEast(_) => match prev_direction {
+                Direction4::North => Some(Direction4::East),
+                Direction4::West => Some(Direction4::South),
+                _ => None,
+            },
+            Pipe::SouthWest(_) => match prev_direction {
+                Direction4::North => Some(Direction4::West),
+                Direction4::East => Some(Direction4::South),
+                _ => None,
+            },
+            Pipe::Start(_) => match prev_direction {
+                Direction4::North => Some(Direction4::North),
+                Direction4::East => Some(Direction4::East),
+                Direction4::South => Some(Direction4::South),
+                Direction4::West => Some(Direction4::West),
+            },
+        }
+    }
+
+    fn is_visited(&self) -> bool {
+        match *self {
+            Pipe::NorthSouth(visited)
+            | Pipe::EastWest(visited)
+            | Pipe::NorthEast(visited)
+            | Pipe::NorthWest(visited)
+            | Pipe::SouthEast(visited)
+            | Pipe::SouthWest(visited)
+            | Pipe::Start(visited) => visited,
+        }
+    }
+}
+
+type Map = Grid<Option<Pipe>>;
+
+/// The directions in which the pipes around `start` connect to it.
+fn start_directions(map: &Map, start: Pos) -> Vec<Direction4> {
+    [
+        Direction4::North,
+        Direction4::South,
+        Direction4::East,
+        Direction4::West,
+    ]
+    .into_iter()
+    .filter(|&direction| {
+        map.neighbour(start, direction)
+            .and_then(|next_index| map[next_index].as_ref())
+            .is_some_and(|pipe| pipe.find_next_direction(direction).is_some())
+    })
+    .collect()
+}
+
+/// Parses the map, which must hold a single start with at least two pipes
+/// connecting to it.
+fn parse_input(input: &str) -> Result<Map, ParseError> {
+    let ctx = Context::new(input);
+    let map = ctx.grid(input, "a pipe or '.'", |c| {
+        Some(match c {
+            '.' => None,
+            '-' => Some(Pipe::EastWest(false)),
+            '|' => Some(Pipe::NorthSouth(false)),
+            'L' => Some(Pipe::NorthEast(false)),
+            'J' => Some(Pipe::NorthWest(false)),
+            'F' => Some(Pipe::SouthEast(false)),
+            '7' => Some(Pipe::SouthWest(false)),
+            'S' => Some(Pipe::Start(true)),
+            _ => return None,
+        })
+    })?;
+    let starts: Vec<Pos> = map
+        .iter()
+        .filter(|(_, pipe)| matches!(pipe, Some(Pipe::Start(_))))
+        .map(|(pos, _)| pos)
+        .collect();
+    // the grid is ASCII, so columns are byte offsets
+    let token = |(row, col): Pos| &input.lines().nth(row).unwrap_or_default()[col..col + 1];
+    match starts[..] {
+        [] => Err(ctx.missing(input, "a start 'S'")),
+        [start] if start_directions(&map, start).len() < 2 => {
+            Err(ctx.error(token(start), "a start connected to two pipes"))
+        }
+        [_] => Ok(map),
+        [_, second, ..] => Err(ctx.error(token(second), "a single start")),
+    }
+}
+
+/// The pipe joining two directions, in the order `start_directions` lists them.
+fn start_pipe(first: Direction4, second: Direction4) -> Pipe {
+    match (first, second) {
+        (Direction4::North, Direction4::East) => Pipe::NorthEast(true),
+        (Direction4::North, Direction4::West) => Pipe::NorthWest(true),
+        (Direction4::South, Direction4::East) => Pipe::SouthEast(true),
+        (Direction4::South, Direction4::West) => Pipe::SouthWest(true),
+        (Direction4::North, Direction4::South) => Pipe::NorthSouth(true),
+        (Direction4::East, Direction4::West) => Pipe::EastWest(true),
+        _ => unreachable!("the directions are distinct and in order"),
+    }
+}
+
+/// Replaces the start with the pipe that closes a loop through it and marks
+/// the loop, trying each pair of pipes connecting to the start. Returns the
+/// length of the loop, `None` if no pair loops back to the start.
+fn find_start(map: &mut Map) -> Option<i32> {
+    let start = map
+        .iter()
+        .find(|(_, pipe)| matches!(pipe, Some(Pipe::Start(_))))
+        .map(|(pos, _)| pos)
+        .expect("the map has a start");
+    let directions = start_directions(map, start);
+    for (i, &first) in directions.iter().enumerate() {
+        for &second in &directions[i + 1..] {
+            let mut attempt = map.clone();
+            attempt[start] = Some(start_pipe(first, second));
+            let Some(next_index) = attempt.neighbour(start, first) else {
+                continue;
+            };
+            let (loop_index, loop_distance) = travel_pipe(&mut attempt, first, next_index, 1);
+            // the walk must come back through the second pipe
+            let closed = loop_index == start
+                && attempt
+                    .neighbour(start, second)
+                    .and_then(|index| attempt[index].as_ref())
+                    .is_some_and(Pipe::is_visited);
+            if closed {
+                *map = attempt;
+                return Some(loop_distance);
+            }
+        }
+    }
+    None
+}
+
+fn travel_pipe(
+    map: &mut Map,
+    direction: Direction4,
+    current_index: Pos,
+    current_distance: i32,
+) -> (Pos, i32) {
+    if let Some(pipe) = &mut map[current_index] {
+        match pipe {
+            Pipe::NorthSouth(false) => *pipe = Pipe::NorthSouth(true),
+            Pipe::EastWest(false) => *pipe = Pipe::EastWest(true),
+            Pipe::NorthEast(false) => *pipe = Pipe::NorthEast(true),
+            Pipe::NorthWest(false) => *pipe = Pipe::NorthWest(true),
+            Pipe::SouthEast(false) => *pipe = Pipe::SouthEast(true),
+            Pipe::SouthWest(false) => *pipe = Pipe::SouthWest(true),
+            Pipe::Start(false) => *pipe = Pipe::Start(true),
+            Pipe::NorthSouth(true) => return (current_index, current_distance),
+            Pipe::EastWest(true) => return (current_index, current_distance),
+            Pipe::NorthEast(true) => return (current_index, current_distance),
+            Pipe::NorthWest(true) => return (current_index, current_distance),
+            Pipe::SouthEast(true) => return (current_index, current_distance),
+            Pipe::SouthWest(true) => return (current_index, current_distance),
+            Pipe::Start(true) => return (current_index, current_distance),
+        };
+        if let Some(next_direction) = pipe.find_next_direction(direction) {
+            if let Some(next_index) = map.neighbour(current_index, next_direction) {
+                travel_pipe(map, next_direction, next_index, current_distance + 1)
+            } else {
+                // dead end because reached edge of map
+                (current_index, current_distance)
+            }
+        } else {
+            // dead end because reached non compatible pipe
+            (current_index, current_distance)
+        }
+    } else {
+        // dead end because reached empty space
+        (current_index, current_distance)
+    }
+}
+
+/// The farthest distance along the loop through the start, and the map with
+/// the loop marked, `None` if the pipes leaving the start do not loop back.
+fn traverse_map(map: &Map) -> Option<(i32, Map)> {
+    let mut map = map.clone();
+    let loop_distance = find_start(&mut map)?;
+    Some((loop_distance / 2, map))
+}
+
+fn farthest_distance(map: &Map) -> Option<i32> {
+    traverse_map(map).map(|(distance, _)| distance)
+}
+
+fn enclosed_tiles(map: &Map) -> Option<i32> {
+    let (_, map) = traverse_map(map)?;
+    let mut result = 0;
+    for row in map.rows() {
+        let mut curr_row_counter = 0;
+        let mut connected_pipe: Option<Pipe> = None;
+        for pipe in row.iter() {
+            if let Some(pipe) = pipe {
+                match pipe {
+                    Pipe::NorthSouth(true) => {
+                        curr_row_counter += 1;
+                        connected_pipe = None;
+                    }
+                    Pipe::SouthEast(true) => {
+                        curr_row_counter += 1;
+                        connected_pipe = Some(Pipe::SouthEast(true));
+                    }
+                    Pipe::SouthWest(true) => match connected_pipe {
+                        Some(Pipe::NorthEast(true)) => (),
+                        _ => curr_row_counter += 1,
+                    },
+                    Pipe::Start(true) => {
+                        curr_row_counter += 1;
+                        connected_pipe = None;
+                    }
+                    Pipe::EastWest(true) => (),
+                    Pipe::NorthEast(true) => {
+                        curr_row_counter += 1;
+                        connected_pipe = Some(Pipe::NorthEast(true));
+                    }
+                    Pipe::NorthWest(true) => match connected_pipe {
+                        Some(Pipe::SouthEast(true)) => (),
+                        _ => curr_row_counter += 1,
+                    },
+                    _ => {
+                        if curr_row_counter % 2 == 1 {
+                            result += 1;
+                        }
+                    }
+                }
+            } else if curr_row_counter % 2 == 1 {
+                result += 1;
+            }
+        }
+        // println!("Result: {}", result);
+    }
+    Some(result)
+}
+
+pub fn day10a(input: &str) -> Result<Option<i32>, ParseError> {
+    Ok(farthest_distance(&parse_input(input)?))
+}
+
+pub fn day10b(input: &str) -> Result<Option<i32>, ParseError> {
+    Ok(enclosed_tiles(&parse_input(input)?))
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    type Parsed<'a> = Map;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a(map: &Self::Parsed<'_>) -> Answer {
+        farthest_distance(map).into()
+    }
+
+    fn part_b(map: &Self::Parsed<'_>) -> Answer {
+        enclosed_tiles(map).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn input1() -> &'static str {
+        ".....
+.S-7.
+.|.|.
+.L-J.
+....."
+    }
+
+    fn input2() -> &'static str {
+        "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ..."
+    }
+
+    #[test]
+    fn test_10a() {
+        let input = input1();
+        assert_eq!(day10a(input), Ok(Some(4)));
+
+        let input = input2();
+        assert_eq!(day10a(input), Ok(Some(8)));
+    }
+
+    fn input3() -> &'static str {
+        "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+..........."
+    }
+
+    fn input4() -> &'static str {
+        "..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+.........."
+    }
+
+    fn input5() -> &'static str {
+        ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ..."
+    }
+
+    fn input6() -> &'static str {
+        "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L"
+    }
+
+    #[test]
+    fn test_10b() {
+        let input = input3();
+        assert_eq!(day10b(input), Ok(Some(4)));
+
+        let input = input4();
+        assert_eq!(day10b(input), Ok(Some(4)));
+
+        let input = input5();
+        assert_eq!(day10b(input), Ok(Some(8)));
+
+        let input = input6();
+        assert_eq!(day10b(input), Ok(Some(10)));
+    }
+
+    #[test]
+    fn test_10_invalid_tile() {
+        let error = day10a(".S-7.\n.|x|.").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.expected, "a pipe or '.'");
+    }
+
+    #[test]
+    fn test_10_invalid_start() {
+        let error = day10a("...\n...\n").unwrap_err();
+        assert_eq!(error.expected, "a start 'S'");
+        let error = day10a(".S-7.\n.|.|.\n.L-JS").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 5));
+        assert_eq!(error.expected, "a single start");
+        let error = day10b(".S-7.\n.....").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 2));
+        assert_eq!(error.expected, "a start connected to two pipes");
+        let error = day10a(&input5()[..4 * 21]).unwrap_err();
+        assert_eq!(error.expected, "a start 'S'");
+    }
+
+    #[test]
+    fn test_10_no_loop() {
+        // the start connects to two pipes that lead nowhere
+        let input = ".S-.\n.|..";
+        assert_eq!(day10a(input), Ok(None));
+        assert_eq!(day10b(input), Ok(None));
+    }
+
+    #[test]
+    fn test_10_extra_start_pipe() {
+        // the pipe west of the start points at it but is not on the loop
+        let input = ".....\n-S-7.\n.|.|.\n.L-J.\n.....";
+        assert_eq!(day10a(input), Ok(Some(4)));
+        assert_eq!(day10b(input), Ok(Some(1)));
+    }
+}
@@ -0,0 +1,127 @@
+use common::grid::{Grid, Pos};
+use common::parse::ParseError;
+use common::solution::{Answer, Solution};
+
+fn parse_input(input: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse(input, "'#' or '.'", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+/// The running count of added rows or columns, given whether each one holds
+/// a galaxy.
+fn expansions(foundgalaxy: impl Iterator<Item = bool>, expansion_size: usize) -> Vec<usize> {
+    foundgalaxy
+        .scan(0, |numexpanded, foundgalaxy| {
+            if !foundgalaxy {
+                *numexpanded += expansion_size;
+            }
+            Some(*numexpanded)
+        })
+        .collect()
+}
+
+fn expand_universe(
+    image: &Grid<bool>,
+    expansion_size: usize,
+) -> (Vec<Pos>, Vec<usize>, Vec<usize>) {
+    let originalgalaxypositions = image
+        .iter()
+        .filter(|(_, &galaxy)| galaxy)
+        .map(|(pos, _)| pos)
+        .collect();
+    let expandedrows = expansions(image.rows().map(|row| row.contains(&true)), expansion_size);
+    let expandedcols = expansions(
+        image.columns().map(|mut col| col.any(|&galaxy| galaxy)),
+        expansion_size,
+    );
+    (originalgalaxypositions, expandedrows, expandedcols)
+}
+
+fn sum_of_distances(
+    originalgalaxypositions: &[Pos],
+    expandedrows: &[usize],
+    expandedcols: &[usize],
+) -> i64 {
+    let mut newgalaxypositions = Vec::new();
+    for (i, j) in originalgalaxypositions {
+        newgalaxypositions.push((i + expandedrows[*i], j + expandedcols[*j]));
+    }
+    let mut result = 0;
+    for (i, galaxy) in newgalaxypositions.iter().enumerate() {
+        for galaxy2 in newgalaxypositions.iter().skip(i + 1) {
+            result += (galaxy.0 as i64 - galaxy2.0 as i64).abs()
+                + (galaxy.1 as i64 - galaxy2.1 as i64).abs();
+        }
+    }
+    result
+}
+
+fn galaxy_distances(image: &Grid<bool>, expansion_size: usize) -> i64 {
+    let expansion_size = (expansion_size - 1).max(1);
+    let (originalgalaxypositions, expandedrows, expandedcols) =
+        expand_universe(image, expansion_size);
+    sum_of_distances(&originalgalaxypositions, &expandedrows, &expandedcols)
+}
+
+pub fn day11(input: &str, expansion_size: usize) -> Result<i64, ParseError> {
+    Ok(galaxy_distances(&parse_input(input)?, expansion_size))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+
+    type Parsed<'a> = Grid<bool>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a(image: &Self::Parsed<'_>) -> Answer {
+        galaxy_distances(image, 1).into()
+    }
+
+    fn part_b(image: &Self::Parsed<'_>) -> Answer {
+        galaxy_distances(image, 1_000_000).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn input() -> &'static str {
+        "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#....."
+    }
+
+    #[test]
+    fn test_11a() {
+        let input = input();
+        assert_eq!(day11(input, 1), Ok(374));
+    }
+
+    #[test]
+    fn test_11b_10() {
+        let input = input();
+        assert_eq!(day11(input, 10), Ok(1030));
+    }
+
+    #[test]
+    fn test_11b_100() {
+        let input = input();
+        assert_eq!(day11(input, 100), Ok(8410));
+    }
+}
@@ -0,0 +1,200 @@
+use common::parse::{Context, ParseError};
+use common::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct Puzzle {
+    pattern: String,
+    groupsizes: Vec<usize>,
+}
+
+fn parse_line<'a>(ctx: &Context<'a>, line: &'a str) -> Result<(&'a str, Vec<usize>), ParseError> {
+    let (pattern, groupsizes) = ctx.split_once(line, " ")?;
+    if let Some((i, c)) = pattern
+        .char_indices()
+        .find(|&(_, c)| !matches!(c, '.' | '#' | '?'))
+    {
+        return Err(ctx.error(&pattern[i..i + c.len_utf8()], "'.', '#' or '?'"));
+    }
+    let groupsizes = groupsizes
+        .split(',')
+        .map(|x| ctx.number::<usize>(x))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((pattern, groupsizes))
+}
+
+fn parse_input(
+    input: &str,
+    new: fn(&str, Vec<usize>) -> Puzzle,
+) -> Result<Vec<Puzzle>, ParseError> {
+    let ctx = Context::new(input);
+    input
+        .lines()
+        .map(|line| {
+            let (pattern, groupsizes) = parse_line(&ctx, line)?;
+            Ok(new(pattern, groupsizes))
+        })
+        .collect()
+}
+
+impl Puzzle {
+    fn new_a(pattern: &str, groupsizes: Vec<usize>) -> Self {
+        Self {
+            pattern: pattern.to_string(),
+            groupsizes,
+        }
+    }
+
+    fn new_b(pattern: &str, groupsizes: Vec<usize>) -> Self {
+        let pattern = std::iter::repeat_n(pattern, 5)
+            .collect::<Vec<_>>()
+            .join("?");
+        let groupsizes = std::iter::repeat_n(groupsizes, 5)
+            .flatten()
+            .collect::<Vec<_>>();
+        Self {
+            pattern,
+            groupsizes,
+        }
+    }
+
+    fn solve(
+        &self,
+        si: usize,
+        ri: usize,
+        neighbor: bool,
+        cache: &mut HashMap<(usize, usize), u64>,
+    ) -> u64 {
+        if let Some(&v) = cache.get(&(si, ri)) {
+            return v;
+        }
+        // out of groups and pattern is exhausted or only remaining are . or ?
+        if ri == self.groupsizes.len()
+            && (si >= self.pattern.len()
+                || self.pattern[si..].chars().all(|c| c == '.' || c == '?'))
+        {
+            cache.insert((si, ri), 1);
+            return 1;
+        }
+
+        // overshot pattern or groups
+        if si >= self.pattern.len() || ri >= self.groupsizes.len() {
+            cache.insert((si, ri), 0);
+            return 0;
+        }
+
+        let val;
+        match self.pattern[si..].chars().next() {
+            Some('.') => return self.solve(si + 1, ri, false, cache),
+            Some('#') => {
+                if neighbor {
+                    return 0;
+                } else if (si + self.groupsizes[ri] <= self.pattern.len())
+                    && self.pattern[si..si + self.groupsizes[ri]]
+                        .chars()
+                        .all(|c| c == '#' || c == '?')
+                {
+                    val = self.solve(si + self.groupsizes[ri], ri + 1, true, cache);
+                } else {
+                    val = 0;
+                }
+            }
+            Some('?') => {
+                if neighbor {
+                    return self.solve(si + 1, ri, false, cache);
+                } else if (si + self.groupsizes[ri] <= self.pattern.len())
+                    && self.pattern[si..si + self.groupsizes[ri]]
+                        .chars()
+                        .all(|c| c == '#' || c == '?')
+                {
+                    val = self.solve(si + 1, ri, false, cache)
+                        + self.solve(si + self.groupsizes[ri], ri + 1, true, cache);
+                } else {
+                    val = self.solve(si + 1, ri, false, cache);
+                }
+            }
+            None => {
+                val = 1;
+            }
+            _ => unreachable!(),
+        }
+        cache.insert((si, ri), val);
+        val
+    }
+}
+
+fn total_arrangements(puzzles: &[Puzzle]) -> u64 {
+    puzzles
+        .iter()
+        .map(|puzzle| {
+            let mut cache = HashMap::new();
+            puzzle.solve(0, 0, false, &mut cache)
+        })
+        .sum()
+}
+
+pub fn day12a(input: &str) -> Result<i32, ParseError> {
+    let puzzles = parse_input(input, Puzzle::new_a)?;
+    Ok(total_arrangements(&puzzles) as i32)
+}
+
+pub fn day12b(input: &str) -> Result<i64, ParseError> {
+    let puzzles = parse_input(input, Puzzle::new_b)?;
+    Ok(total_arrangements(&puzzles) as i64)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+
+    type Parsed<'a> = (Vec<Puzzle>, Vec<Puzzle>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok((
+            parse_input(input, Puzzle::new_a)?,
+            parse_input(input, Puzzle::new_b)?,
+        ))
+    }
+
+    fn part_a((puzzles, _): &Self::Parsed<'_>) -> Answer {
+        total_arrangements(puzzles).into()
+    }
+
+    fn part_b((_, puzzles): &Self::Parsed<'_>) -> Answer {
+        total_arrangements(puzzles).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn input() -> &'static str {
+        "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1"
+    }
+
+    #[test]
+    fn test_12a() {
+        let input = input();
+        assert_eq!(day12a(input), Ok(21));
+    }
+
+    #[test]
+    fn test_12b() {
+        let input = input();
+        assert_eq!(day12b(input), Ok(525152));
+    }
+
+    #[test]
+    fn test_12_invalid_spring() {
+        let error = day12a("#é. 1").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 2));
+        assert_eq!(error.token, "é");
+    }
+}
@@ -0,0 +1,140 @@
+use common::grid::Grid;
+use common::parse::{Context, ParseError};
+use common::solution::{Answer, Solution};
+use std::iter::zip;
+
+fn split_and_zip(
+    input: &[Vec<bool>],
+    index: usize,
+) -> impl Iterator<Item = (&Vec<bool>, &Vec<bool>)> {
+    let (leftside, rightside) = input.split_at(index);
+    zip(leftside.iter().rev(), rightside)
+}
+
+// my pre 13b solution to 13a
+// fn valid_index(input: &Vec<String>, index: usize) -> bool {
+//     split_and_zip(input, index).all(|(left, right)| left == right)
+// }
+
+fn valid_index(input: &[Vec<bool>], index: usize, differences: usize) -> bool {
+    split_and_zip(input, index)
+        .map(|(left, right)| {
+            zip(left, right).fold(0, |acc, (l, r)| if l != r { acc + 1 } else { acc })
+        })
+        .sum::<usize>()
+        == differences
+}
+
+fn find_mirror(input: Vec<Vec<bool>>, differences: usize) -> usize {
+    if let Some(result) = (1..input.len()).find(|i| valid_index(&input, *i, differences)) {
+        return result;
+    }
+    0
+}
+
+fn parse_pattern<'a>(ctx: &Context<'a>, pattern: &'a str) -> Result<Grid<bool>, ParseError> {
+    ctx.grid(pattern, "'#' or '.'", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Grid<bool>>, ParseError> {
+    let ctx = Context::new(input);
+    input
+        .split("\n\n")
+        .map(|pattern| parse_pattern(&ctx, pattern))
+        .collect()
+}
+
+fn summarize(pattern: &Grid<bool>, differences: usize) -> usize {
+    let rows = pattern.rows().map(<[bool]>::to_vec).collect();
+    let cols = pattern
+        .transpose()
+        .rows()
+        .map(|col| col.copied().collect())
+        .collect();
+
+    100 * find_mirror(rows, differences) + find_mirror(cols, differences)
+}
+
+fn total(patterns: &[Grid<bool>], differences: usize) -> usize {
+    patterns
+        .iter()
+        .fold(0, |acc, pattern| acc + summarize(pattern, differences))
+}
+
+pub fn day13(input: &str, differences: usize) -> Result<usize, ParseError> {
+    let ctx = Context::new(input);
+    Ok(summarize(&parse_pattern(&ctx, input)?, differences))
+}
+
+pub fn day13total(input: &str, differences: usize) -> Result<usize, ParseError> {
+    Ok(total(&parse_input(input)?, differences))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+
+    type Parsed<'a> = Vec<Grid<bool>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a(patterns: &Self::Parsed<'_>) -> Answer {
+        total(patterns, 0).into()
+    }
+
+    fn part_b(patterns: &Self::Parsed<'_>) -> Answer {
+        total(patterns, 1).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn input1() -> &'static str {
+        "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#."
+    }
+
+    fn input2() -> &'static str {
+        "#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#"
+    }
+
+    #[test]
+    fn test_13a1() {
+        let input = input1();
+        assert_eq!(day13(input, 0), Ok(5));
+    }
+
+    #[test]
+    fn test_13a2() {
+        let input = input2();
+        assert_eq!(day13(input, 0), Ok(400));
+    }
+
+    #[test]
+    fn test_13atotal() {
+        let input1 = input1();
+        let input2 = input2();
+        let input = &format!("{}\n\n{}", input1, input2);
+        assert_eq!(day13total(input, 0), Ok(405));
+    }
+}
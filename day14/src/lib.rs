@@ -0,0 +1,233 @@
+use common::geometry::Direction4;
+use common::grid::Grid;
+use common::parse::ParseError;
+use common::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+//implement Copy trait
+#[derive(Copy, Clone, Debug)]
+#[allow(dead_code)]
+enum Rock {
+    Round,
+    Cube,
+}
+
+#[allow(dead_code)]
+#[derive(Clone)]
+pub struct Platform {
+    rocks: Grid<Option<Rock>>,
+    direction: Direction4,
+}
+
+impl Platform {
+    fn new(input: &str) -> Result<Platform, ParseError> {
+        Ok(Platform {
+            rocks: Grid::parse(input, "'O', '#' or '.'", |c| {
+                Some(match c {
+                    'O' => Some(Rock::Round),
+                    '#' => Some(Rock::Cube),
+                    '.' => None,
+                    _ => return None,
+                })
+            })?,
+            direction: Direction4::West,
+        })
+    }
+
+    fn transpose(&mut self) {
+        self.rocks = self.rocks.transpose().to_grid();
+    }
+
+    fn reverse_columns(&mut self) {
+        self.rocks = self.rocks.flip_horizontal().to_grid();
+    }
+
+    fn reverse_rows(&mut self) {
+        self.rocks = self.rocks.flip_vertical().to_grid();
+    }
+
+    fn change_direction(&mut self, direction: Direction4) {
+        if self.direction == direction {
+            return;
+        }
+        self.direction = match self.direction {
+            Direction4::North => match direction {
+                Direction4::West => {
+                    self.transpose();
+                    Direction4::West
+                }
+                _ => unreachable!(),
+            },
+            Direction4::East => match direction {
+                Direction4::North => {
+                    self.transpose();
+                    self.reverse_rows();
+                    Direction4::North
+                }
+                Direction4::West => {
+                    self.reverse_columns();
+                    Direction4::West
+                }
+                _ => unreachable!(),
+            },
+            Direction4::South => match direction {
+                Direction4::East => {
+                    self.transpose();
+                    self.reverse_rows();
+                    self.reverse_columns();
+                    Direction4::East
+                }
+                _ => unreachable!(),
+            },
+            Direction4::West => match direction {
+                Direction4::North => {
+                    self.transpose();
+                    Direction4::North
+                }
+                Direction4::South => {
+                    self.reverse_rows();
+                    self.transpose();
+                    Direction4::South
+                }
+                _ => unreachable!(),
+            },
+        }
+    }
+
+    fn tilt(&mut self) {
+        for i in 0..self.rocks.height() {
+            let mut last_empty_space = 0;
+            for j in 0..self.rocks.width() {
+                if let Some(rock) = self.rocks[(i, j)] {
+                    match rock {
+                        Rock::Round => {
+                            self.rocks[(i, j)] = None;
+                            self.rocks[(i, last_empty_space)] = Some(Rock::Round);
+                            last_empty_space += 1;
+                        }
+                        Rock::Cube => {
+                            last_empty_space = j + 1;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn get_load(mut self) -> i32 {
+        self.change_direction(Direction4::West);
+        self.rocks
+            .flip_vertical()
+            .rows()
+            .enumerate()
+            .fold(0, |acc, (i, mut row)| {
+                row.by_ref()
+                    .filter(|rock| matches!(rock, Some(Rock::Round)))
+                    .count() as i32
+                    * (i as i32 + 1)
+                    + acc
+            })
+    }
+
+    fn get_str(&self) -> String {
+        self.rocks
+            .map(|rock| match rock {
+                Some(Rock::Round) => 'O',
+                Some(Rock::Cube) => '#',
+                None => '.',
+            })
+            .to_string()
+    }
+}
+
+fn load_after_tilt(platform: &Platform) -> i32 {
+    let mut platform = platform.clone();
+    platform.change_direction(Direction4::North);
+    platform.tilt();
+    platform.get_load()
+}
+
+fn load_after_cycles(platform: &Platform) -> i32 {
+    let mut platform = platform.clone();
+    let mut states = HashMap::new();
+    let mut early_exit: Option<usize> = None;
+    for i in 0..1_000_000_000 {
+        if early_exit.map(|value| value == i).unwrap_or(false) {
+            break;
+        }
+        for direction in [
+            Direction4::North,
+            Direction4::West,
+            Direction4::South,
+            Direction4::East,
+        ] {
+            platform.change_direction(direction);
+            platform.tilt();
+        }
+        let platform_state = platform.get_str();
+        if let Some(first) = states.get(&platform_state) {
+            let cycle = i - first;
+            early_exit.replace(i + (1_000_000_000 - i) % cycle);
+        }
+        states.insert(platform_state, i);
+    }
+    platform.get_load()
+}
+
+pub fn day14a(input: &str) -> Result<i32, ParseError> {
+    Ok(load_after_tilt(&Platform::new(input)?))
+}
+
+pub fn day14b(input: &str) -> Result<i32, ParseError> {
+    Ok(load_after_cycles(&Platform::new(input)?))
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+
+    type Parsed<'a> = Platform;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Platform::new(input)
+    }
+
+    fn part_a(platform: &Self::Parsed<'_>) -> Answer {
+        load_after_tilt(platform).into()
+    }
+
+    fn part_b(platform: &Self::Parsed<'_>) -> Answer {
+        load_after_cycles(platform).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn input() -> &'static str {
+        "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#...."
+    }
+
+    #[test]
+    fn test_xa() {
+        let input = input();
+        assert_eq!(day14a(input), Ok(136));
+    }
+
+    #[test]
+    fn test_xb() {
+        let input = input();
+        assert_eq!(day14b(input), Ok(64));
+    }
+}
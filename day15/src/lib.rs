@@ -0,0 +1,129 @@
+use common::parse::{Context, ParseError};
+use common::solution::{Answer, Solution};
+
+fn hashfn(input: &str) -> usize {
+    input
+        .as_bytes()
+        .iter()
+        .fold(0, |acc, b| ((acc + *b as usize) * 17) % 256)
+}
+
+pub enum Operation<'a> {
+    Remove(&'a str),
+    Insert(&'a str, u8),
+}
+
+pub struct Step<'a> {
+    text: &'a str,
+    operation: Operation<'a>,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Step<'_>>, ParseError> {
+    let ctx = Context::new(input);
+    input
+        .trim_end_matches('\n')
+        .split(',')
+        .map(|text| {
+            let operation = if let Some(label) = text.strip_suffix('-') {
+                Operation::Remove(label)
+            } else {
+                let (label, focal_point) = ctx.split_once(text, "=")?;
+                Operation::Insert(label, ctx.number(focal_point)?)
+            };
+            Ok(Step { text, operation })
+        })
+        .collect()
+}
+
+fn sum_of_hashes(steps: &[Step]) -> usize {
+    steps.iter().fold(0, |acc, step| acc + hashfn(step.text))
+}
+
+pub fn day15a(input: &str) -> Result<usize, ParseError> {
+    Ok(sum_of_hashes(&parse_input(input)?))
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct Lense {
+    label: String,
+    focal_point: u8,
+}
+
+fn focusing_power(steps: &[Step]) -> usize {
+    let mut hashmap: Vec<Vec<Lense>> = vec![vec![]; 256];
+    for step in steps {
+        match step.operation {
+            Operation::Remove(label) => {
+                let hash = hashfn(label);
+                hashmap[hash].retain(|lense| lense.label != label);
+            }
+            Operation::Insert(label, focal_point) => {
+                let hash = hashfn(label);
+                let mut found_label = false;
+                for i in 0..hashmap[hash].len() {
+                    if hashmap[hash][i].label == label {
+                        hashmap[hash][i].focal_point = focal_point;
+                        found_label = true;
+                        break;
+                    }
+                }
+                if !found_label {
+                    hashmap[hash].push(Lense {
+                        label: label.to_string(),
+                        focal_point,
+                    })
+                }
+            }
+        }
+    }
+    hashmap.iter().enumerate().fold(0, |acc, (i, lense)| {
+        acc + lense.iter().enumerate().fold(0, |acc, (j, lense)| {
+            acc + (i + 1) * (j + 1) * lense.focal_point as usize
+        })
+    })
+}
+
+pub fn day15b(input: &str) -> Result<usize, ParseError> {
+    Ok(focusing_power(&parse_input(input)?))
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+
+    type Parsed<'a> = Vec<Step<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a(steps: &Self::Parsed<'_>) -> Answer {
+        sum_of_hashes(steps).into()
+    }
+
+    fn part_b(steps: &Self::Parsed<'_>) -> Answer {
+        focusing_power(steps).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn input() -> &'static str {
+        "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7"
+    }
+
+    #[test]
+    fn test_xa() {
+        let input = input();
+        assert_eq!(day15a(input), Ok(1320));
+    }
+    #[test]
+    fn test_xb() {
+        let input = input();
+        assert_eq!(day15b(input), Ok(145));
+    }
+}
@@ -0,0 +1,181 @@
+use common::geometry::Direction4;
+use common::grid::{Grid, Pos};
+use common::parse::ParseError;
+use common::solution::{Answer, Solution};
+
+#[derive(Debug)]
+pub enum Mirror {
+    HorizontalSplitter,
+    VerticalSplitter,
+    Slash,
+    Backslash,
+    Empty,
+}
+
+struct State {
+    beam: Direction4,
+    pos: Option<Pos>,
+    beams: Grid<Vec<Direction4>>,
+}
+
+impl State {
+    fn new(beam: Direction4, pos: Pos, beams: Grid<Vec<Direction4>>) -> Self {
+        Self {
+            beam,
+            pos: Some(pos),
+            beams,
+        }
+    }
+
+    fn move_space(&mut self, map: &Grid<Mirror>) {
+        self.pos = self.pos.and_then(|pos| map.neighbour(pos, self.beam));
+    }
+
+    fn reflect(&mut self, map: &Grid<Mirror>) {
+        while let Some((pos, mirror)) = self.get_valid_pos(map) {
+            self.beams[pos].push(self.beam);
+            match mirror {
+                Mirror::Empty => (),
+                Mirror::HorizontalSplitter | Mirror::VerticalSplitter => {
+                    if self.beam.is_horizontal() != matches!(mirror, Mirror::HorizontalSplitter) {
+                        let beam = self.beam.turn_left();
+                        self.beam = beam;
+                        self.reflect(map);
+                        self.pos = Some(pos);
+                        self.beam = beam.opposite();
+                    }
+                }
+                Mirror::Slash if self.beam.is_horizontal() => self.beam = self.beam.turn_left(),
+                Mirror::Slash => self.beam = self.beam.turn_right(),
+                Mirror::Backslash if self.beam.is_horizontal() => {
+                    self.beam = self.beam.turn_right()
+                }
+                Mirror::Backslash => self.beam = self.beam.turn_left(),
+            }
+            self.move_space(map);
+        }
+    }
+    fn get_valid_pos<'a>(&self, map: &'a Grid<Mirror>) -> Option<(Pos, &'a Mirror)> {
+        // beam left the map
+        let pos = self.pos?;
+        // same beam already passed through
+        if self.beams[pos].iter().all(|beam| *beam != self.beam) {
+            Some((pos, &map[pos]))
+        } else {
+            None
+        }
+    }
+
+    fn num_energized(&self) -> i32 {
+        self.beams.iter().filter(|(_, x)| !x.is_empty()).count() as i32
+    }
+}
+
+fn parse_input(input: &str) -> Result<Grid<Mirror>, ParseError> {
+    Grid::parse(input, "a mirror, a splitter or '.'", |c| {
+        Some(match c {
+            '.' => Mirror::Empty,
+            '-' => Mirror::HorizontalSplitter,
+            '|' => Mirror::VerticalSplitter,
+            '/' => Mirror::Slash,
+            '\\' => Mirror::Backslash,
+            _ => return None,
+        })
+    })
+}
+
+fn energized_from_corner(map: &Grid<Mirror>) -> i32 {
+    let beam = Direction4::East;
+    let beams = Grid::filled(map.width(), map.height(), vec![]);
+    let mut state = State::new(beam, (0, 0), beams);
+    state.reflect(map);
+    state.num_energized()
+}
+
+fn most_energized(map: &Grid<Mirror>) -> i32 {
+    let beams = Grid::filled(map.width(), map.height(), vec![]);
+    let mut result = 0;
+    for i in 0..map.height() {
+        let beam = Direction4::East;
+        let mut state = State::new(beam, (i, 0), beams.clone());
+        state.reflect(map);
+        result = state.num_energized().max(result);
+
+        let beam = Direction4::West;
+        let mut state = State::new(beam, (i, map.width() - 1), beams.clone());
+        state.reflect(map);
+        result = state.num_energized().max(result);
+    }
+
+    for j in 0..map.width() {
+        let beam = Direction4::South;
+        let mut state = State::new(beam, (0, j), beams.clone());
+        state.reflect(map);
+        result = state.num_energized().max(result);
+
+        let beam = Direction4::North;
+        let mut state = State::new(beam, (map.height() - 1, j), beams.clone());
+        state.reflect(map);
+        result = state.num_energized().max(result);
+    }
+
+    result
+}
+
+pub fn day16a(input: &str) -> Result<i32, ParseError> {
+    Ok(energized_from_corner(&parse_input(input)?))
+}
+
+pub fn day16b(input: &str) -> Result<i32, ParseError> {
+    Ok(most_energized(&parse_input(input)?))
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+
+    type Parsed<'a> = Grid<Mirror>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a(map: &Self::Parsed<'_>) -> Answer {
+        energized_from_corner(map).into()
+    }
+
+    fn part_b(map: &Self::Parsed<'_>) -> Answer {
+        most_energized(map).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn input() -> &'static str {
+        r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|...."
+    }
+
+    #[test]
+    fn test_16a() {
+        let input = input();
+        assert_eq!(day16a(input), Ok(46));
+    }
+
+    #[test]
+    fn test_16b() {
+        let input = input();
+        assert_eq!(day16b(input), Ok(51));
+    }
+}
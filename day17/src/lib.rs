@@ -0,0 +1,241 @@
+#![warn(clippy::pedantic)]
+
+use common::geometry::Direction4;
+use common::grid::{Grid, Pos};
+use common::parse::ParseError;
+use common::solution::{Answer, Solution};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+#[derive(Eq, Debug, Copy, Clone, PartialEq)]
+struct State {
+    cost: u32,
+    position: (usize, usize, usize, usize),
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+struct AdjacencyMatrix {
+    adj_mat: Grid<States>,
+}
+
+struct States {
+    states: Vec<Vec<Vec<State>>>,
+}
+
+fn shortest_path(
+    adj_mat: AdjacencyMatrix,
+    start: (usize, usize, usize, usize),
+    goals: &[(usize, usize, usize, usize)],
+) -> Option<u32> {
+    let adj_mat = adj_mat.adj_mat;
+    let mut dist: Vec<Vec<Vec<Vec<u32>>>> =
+        vec![
+            vec![vec![vec![u32::MAX; adj_mat[(0, 0)].states[0].len()]; 4]; adj_mat.width()];
+            adj_mat.height()
+        ];
+    let mut heap = BinaryHeap::new();
+
+    dist[start.0][start.1][start.2][start.3] = 0;
+    heap.push(State {
+        cost: 0,
+        position: start,
+    });
+
+    while let Some(State { cost, position }) = heap.pop() {
+        if goals.contains(&position) {
+            return Some(cost);
+        }
+
+        if cost > dist[position.0][position.1][position.2][position.3] {
+            continue;
+        }
+
+        for node in &adj_mat[(position.0, position.1)].states[position.2][position.3] {
+            let next = State {
+                cost: node.cost + cost,
+                position: node.position,
+            };
+
+            if next.cost < dist[next.position.0][next.position.1][next.position.2][next.position.3]
+            {
+                heap.push(next);
+                dist[next.position.0][next.position.1][next.position.2][next.position.3] =
+                    next.cost;
+            }
+        }
+    }
+    None
+}
+
+fn eligible_directions(
+    prev_direction: Direction4,
+    blocks_moved: usize,
+    min_same_dir: usize,
+    max_same_dir: usize,
+) -> Vec<Direction4> {
+    if blocks_moved < min_same_dir {
+        return vec![prev_direction];
+    }
+    let mut eligible_directions = Direction4::ALL.to_vec();
+    let opposite_direction = prev_direction.opposite();
+
+    eligible_directions.retain(|d| *d != opposite_direction);
+
+    if blocks_moved >= max_same_dir {
+        eligible_directions.retain(|d| *d != prev_direction);
+    }
+
+    eligible_directions
+}
+
+fn move_in_direction(pos: Pos, direction: Direction4, map: &Grid<u32>) -> Option<(Pos, u32)> {
+    let pos = map.neighbour(pos, direction)?;
+    Some((pos, map[pos]))
+}
+
+fn find_adjacent_nodes(
+    pos: Pos,
+    prev_direction: Direction4,
+    blocks_moved: usize,
+    map: &Grid<u32>,
+    min_same_dir: usize,
+    max_same_dir: usize,
+) -> Vec<State> {
+    let mut adjacent_nodes = Vec::new();
+    let eligible_directions =
+        eligible_directions(prev_direction, blocks_moved, min_same_dir, max_same_dir);
+    for direction in eligible_directions {
+        if let Some(((new_row_index, new_col_index), value)) =
+            move_in_direction(pos, direction, map)
+        {
+            let mut new_blocks_moved = 1;
+            if direction == prev_direction {
+                new_blocks_moved += blocks_moved;
+            }
+            adjacent_nodes.push(State {
+                position: (
+                    new_row_index,
+                    new_col_index,
+                    direction as usize,
+                    new_blocks_moved,
+                ),
+                cost: value,
+            });
+        }
+    }
+    adjacent_nodes
+}
+
+fn parse_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(input, "a digit", |c| c.to_digit(10))
+}
+
+fn adjacency_matrix(map: &Grid<u32>, min_same_dir: usize, max_same_dir: usize) -> AdjacencyMatrix {
+    let adj_mat = map
+        .positions()
+        .map(|pos| {
+            let mut states = vec![vec![vec![]; max_same_dir + 1]; 4];
+            for direction in Direction4::ALL {
+                for (l, adjacent_nodes) in states[direction as usize].iter_mut().enumerate() {
+                    *adjacent_nodes =
+                        find_adjacent_nodes(pos, direction, l, map, min_same_dir, max_same_dir);
+                }
+            }
+            States { states }
+        })
+        .collect();
+    AdjacencyMatrix {
+        adj_mat: Grid::new(map.width(), map.height(), adj_mat),
+    }
+}
+
+fn min_heat_loss(map: &Grid<u32>, min_same_dir: usize, max_same_dir: usize) -> Option<u32> {
+    let adj_mat = adjacency_matrix(map, min_same_dir, max_same_dir);
+
+    let start_node = (0, 0, Direction4::East as usize, 0);
+    let mut end_nodes = Vec::new();
+    for direction in Direction4::ALL {
+        for l in min_same_dir..=max_same_dir {
+            end_nodes.push((map.height() - 1, map.width() - 1, direction as usize, l));
+        }
+    }
+    shortest_path(adj_mat, start_node, &end_nodes)
+}
+
+/// # Errors
+///
+/// Returns an error if the map contains anything but digits.
+pub fn day17(
+    input: &str,
+    min_same_dir: usize,
+    max_same_dir: usize,
+) -> Result<Option<u32>, ParseError> {
+    Ok(min_heat_loss(
+        &parse_input(input)?,
+        min_same_dir,
+        max_same_dir,
+    ))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+
+    type Parsed<'a> = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a(map: &Self::Parsed<'_>) -> Answer {
+        min_heat_loss(map, 0, 3).into()
+    }
+
+    fn part_b(map: &Self::Parsed<'_>) -> Answer {
+        min_heat_loss(map, 4, 10).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn input() -> &'static str {
+        "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533"
+    }
+
+    #[test]
+    fn test_17a() {
+        let input = input();
+        assert_eq!(day17(input, 0, 3), Ok(Some(102)));
+    }
+    #[test]
+    fn test_17b() {
+        let input = input();
+        assert_eq!(day17(input, 4, 10), Ok(Some(94)));
+    }
+}
@@ -0,0 +1,177 @@
+#![warn(clippy::pedantic)]
+
+use common::geometry::{Direction4, Point};
+use common::parse::{Context, ParseError};
+use common::solution::{Answer, Solution};
+
+fn get_coords(input: &[(Direction4, i64)]) -> Vec<Point<i64>> {
+    let mut coords = vec![Point::default()];
+    for &(direction, distance) in input {
+        coords.push(coords[coords.len() - 1].step(direction, distance));
+    }
+    coords
+}
+
+fn polynomial_area(coords: &[Point<i64>]) -> i64 {
+    let mut area = 0;
+    for i in 0..coords.len() - 1 {
+        area += coords[i].row * coords[i + 1].col - coords[i + 1].row * coords[i].col;
+    }
+
+    area.abs() / 2
+}
+
+fn polynomial_perimeter(coords: &[Point<i64>]) -> i64 {
+    let mut perimeter = 0;
+    for i in 0..coords.len() - 1 {
+        perimeter += coords[i].manhattan(coords[i + 1]);
+    }
+
+    perimeter
+}
+
+fn parse_step_a<'a>(ctx: &Context<'a>, line: &'a str) -> Result<(Direction4, i64), ParseError> {
+    let mut split_line = line.splitn(3, ' ');
+    let direction = ctx.next(&mut split_line, line, "a direction")?;
+    let direction = match direction {
+        "U" => Direction4::North,
+        "D" => Direction4::South,
+        "L" => Direction4::West,
+        "R" => Direction4::East,
+        _ => return Err(ctx.error(direction, "'U', 'D', 'L' or 'R'")),
+    };
+    let distance = ctx.number(ctx.next(&mut split_line, line, "a distance")?)?;
+    Ok((direction, distance))
+}
+
+fn parse_step_b<'a>(ctx: &Context<'a>, line: &'a str) -> Result<(Direction4, i64), ParseError> {
+    let color = ctx.next(&mut line.splitn(3, ' ').skip(2), line, "a color")?;
+    let hex = ctx.strip_suffix(ctx.strip_prefix(color, "(#")?, ")")?;
+    if hex.len() != 6 || !hex.is_ascii() {
+        return Err(ctx.error(hex, "six hexadecimal digits"));
+    }
+    let (distance, direction) = hex.split_at(5);
+    Ok((
+        match direction {
+            "0" => Direction4::East,
+            "1" => Direction4::South,
+            "2" => Direction4::West,
+            "3" => Direction4::North,
+            _ => return Err(ctx.error(direction, "'0', '1', '2' or '3'")),
+        },
+        i64::from_str_radix(distance, 16)
+            .map_err(|_| ctx.error(distance, "a hexadecimal number"))?,
+    ))
+}
+
+type ParseStep = for<'a> fn(&Context<'a>, &'a str) -> Result<(Direction4, i64), ParseError>;
+
+fn parse_input(input: &str, parse_step: ParseStep) -> Result<Vec<(Direction4, i64)>, ParseError> {
+    let ctx = Context::new(input);
+    let dig_plan = input
+        .lines()
+        .map(|line| parse_step(&ctx, line))
+        .collect::<Result<Vec<_>, _>>()?;
+    let end = dig_plan
+        .iter()
+        .fold(Point::default(), |point, &(direction, distance)| {
+            point.step(direction, distance)
+        });
+    if end != Point::default() {
+        return Err(ctx.missing(input.trim_end(), "a dig plan that returns to the start"));
+    }
+    Ok(dig_plan)
+}
+
+fn lagoon_size(dig_plan: &[(Direction4, i64)]) -> i64 {
+    let coords = get_coords(dig_plan);
+    polynomial_area(&coords) + polynomial_perimeter(&coords) / 2 + 1
+}
+
+/// # Errors
+///
+/// Returns an error if a step of the dig plan is malformed or the plan does not
+/// return to the start.
+pub fn day18a(input: &str) -> Result<i64, ParseError> {
+    Ok(lagoon_size(&parse_input(input, parse_step_a)?))
+}
+
+/// # Errors
+///
+/// Returns an error if a step of the dig plan is malformed or the plan does not
+/// return to the start.
+pub fn day18b(input: &str) -> Result<i64, ParseError> {
+    Ok(lagoon_size(&parse_input(input, parse_step_b)?))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+
+    type Parsed<'a> = (Vec<(Direction4, i64)>, Vec<(Direction4, i64)>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok((
+            parse_input(input, parse_step_a)?,
+            parse_input(input, parse_step_b)?,
+        ))
+    }
+
+    fn part_a((dig_plan, _): &Self::Parsed<'_>) -> Answer {
+        lagoon_size(dig_plan).into()
+    }
+
+    fn part_b((_, dig_plan): &Self::Parsed<'_>) -> Answer {
+        lagoon_size(dig_plan).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn input() -> &'static str {
+        "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)"
+    }
+
+    #[test]
+    fn test_18a() {
+        let input = input();
+        assert_eq!(day18a(input), Ok(62));
+    }
+
+    #[test]
+    fn test_18b() {
+        let input = input();
+        assert_eq!(day18b(input), Ok(952_408_144_115));
+    }
+
+    #[test]
+    fn test_18_invalid_color() {
+        let error = day18b("R 6 (#70c710)\nD 5 (#0dc5x1)").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(error.expected, "a hexadecimal number");
+    }
+
+    #[test]
+    fn test_18_open_plan() {
+        let input = input().lines().take(5).collect::<Vec<_>>().join("\n");
+        let error = day18a(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (5, 14));
+        assert_eq!(error.expected, "a dig plan that returns to the start");
+    }
+}
@@ -0,0 +1,377 @@
+#![warn(clippy::pedantic)]
+#![allow(dead_code)]
+
+use common::parse::{Context, ParseError};
+use common::solution::{Answer, Solution};
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+#[derive(Debug)]
+pub struct Part {
+    x: i32,
+    m: i32,
+    a: i32,
+    s: i32,
+}
+
+#[derive(Debug)]
+enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+#[derive(Debug)]
+enum Operator {
+    LessThan,
+    GreaterThan,
+}
+
+#[derive(Debug)]
+pub struct Condition {
+    category: Category,
+    operator: Operator,
+    value: i32,
+    destination: String,
+}
+
+#[derive(Debug)]
+pub enum Rule {
+    Condition(Condition),
+    Destination(String),
+}
+
+type Workflows = HashMap<String, Vec<Rule>>;
+
+/// All parts whose ratings lie in the given range of each category.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartRange {
+    pub x: RangeInclusive<i32>,
+    pub m: RangeInclusive<i32>,
+    pub a: RangeInclusive<i32>,
+    pub s: RangeInclusive<i32>,
+}
+
+impl Default for PartRange {
+    /// Every possible part, rated from 1 to 4000 in each category.
+    fn default() -> Self {
+        PartRange {
+            x: 1..=4000,
+            m: 1..=4000,
+            a: 1..=4000,
+            s: 1..=4000,
+        }
+    }
+}
+
+impl PartRange {
+    fn get_mut(&mut self, category: &Category) -> &mut RangeInclusive<i32> {
+        match category {
+            Category::X => &mut self.x,
+            Category::M => &mut self.m,
+            Category::A => &mut self.a,
+            Category::S => &mut self.s,
+        }
+    }
+
+    /// The number of distinct parts in the range.
+    #[must_use]
+    pub fn combinations(&self) -> i64 {
+        [&self.x, &self.m, &self.a, &self.s]
+            .iter()
+            .map(|range| i64::from(range.end() - range.start() + 1).max(0))
+            .product()
+    }
+
+    /// Splits the range into the parts that meet `condition` and the rest,
+    /// either of which may be empty.
+    fn split(&self, condition: &Condition) -> (Option<PartRange>, Option<PartRange>) {
+        let mut range = self.clone();
+        let ratings = range.get_mut(&condition.category);
+        let (start, end) = (*ratings.start(), *ratings.end());
+        let (matching, rest) = match condition.operator {
+            Operator::LessThan => (
+                start..=end.min(condition.value - 1),
+                start.max(condition.value)..=end,
+            ),
+            Operator::GreaterThan => (
+                start.max(condition.value + 1)..=end,
+                start..=end.min(condition.value),
+            ),
+        };
+        let with = |ratings: RangeInclusive<i32>| {
+            (!ratings.is_empty()).then(|| {
+                let mut range = range.clone();
+                *range.get_mut(&condition.category) = ratings;
+                range
+            })
+        };
+        (with(matching), with(rest))
+    }
+}
+
+fn find_destination(workflows: &Workflows, part: &Part, source: &str) -> String {
+    workflows
+        .get(source)
+        .and_then(|rules| {
+            rules.iter().find_map(|rule| match rule {
+                Rule::Condition(condition) => {
+                    let value = match condition.category {
+                        Category::X => part.x,
+                        Category::M => part.m,
+                        Category::A => part.a,
+                        Category::S => part.s,
+                    };
+                    let result = match condition.operator {
+                        Operator::LessThan => value < condition.value,
+                        Operator::GreaterThan => value > condition.value,
+                    };
+                    if result && (condition.destination == "A" || condition.destination == "R") {
+                        Some(condition.destination.clone())
+                    } else if result {
+                        Some(find_destination(workflows, part, &condition.destination))
+                    } else {
+                        None
+                    }
+                }
+                Rule::Destination(dest) if dest == "A" || dest == "R" => Some(dest.clone()),
+                Rule::Destination(dest) => Some(find_destination(workflows, part, dest)),
+            })
+        })
+        .unwrap_or_default()
+}
+
+fn collect_accepted(
+    workflows: &Workflows,
+    source: &str,
+    mut range: PartRange,
+    accepted: &mut Vec<PartRange>,
+) {
+    if source == "A" {
+        accepted.push(range);
+        return;
+    }
+    let Some(rules) = workflows.get(source) else {
+        return;
+    };
+    for rule in rules {
+        match rule {
+            Rule::Condition(condition) => {
+                let (matching, rest) = range.split(condition);
+                if let Some(matching) = matching {
+                    collect_accepted(workflows, &condition.destination, matching, accepted);
+                }
+                match rest {
+                    Some(rest) => range = rest,
+                    None => return,
+                }
+            }
+            Rule::Destination(dest) => {
+                collect_accepted(workflows, dest, range, accepted);
+                return;
+            }
+        }
+    }
+}
+
+/// The disjoint ranges of parts that are accepted when starting at the `in`
+/// workflow, found by splitting the range of every possible part at each
+/// condition on the way.
+#[must_use]
+pub fn accepted_ranges(workflows: &Workflows) -> Vec<PartRange> {
+    let mut accepted = Vec::new();
+    collect_accepted(workflows, "in", PartRange::default(), &mut accepted);
+    accepted
+}
+
+fn accepted_combinations(workflows: &Workflows) -> i64 {
+    accepted_ranges(workflows)
+        .iter()
+        .map(PartRange::combinations)
+        .sum()
+}
+
+fn parse_rule<'a>(ctx: &Context<'a>, rule: &'a str) -> Result<Rule, ParseError> {
+    let Some((comp, dest)) = rule.split_once(':') else {
+        return Ok(Rule::Destination(rule.to_string()));
+    };
+    let category = match comp.get(..1) {
+        Some("x") => Category::X,
+        Some("m") => Category::M,
+        Some("a") => Category::A,
+        Some("s") => Category::S,
+        _ => return Err(ctx.error(comp, "'x', 'm', 'a' or 's'")),
+    };
+    let operator = match comp.get(1..2) {
+        Some(">") => Operator::GreaterThan,
+        Some("<") => Operator::LessThan,
+        _ => return Err(ctx.error(comp.get(1..).unwrap_or(comp), "'<' or '>'")),
+    };
+    Ok(Rule::Condition(Condition {
+        category,
+        operator,
+        value: ctx.number(&comp[2..])?,
+        destination: dest.to_string(),
+    }))
+}
+
+fn parse_workflows<'a>(ctx: &Context<'a>, input: &'a str) -> Result<Workflows, ParseError> {
+    let mut workflows = Workflows::new();
+    for line in input.lines() {
+        let (code, workflow) = ctx.split_once(line, "{")?;
+        let workflow = ctx.strip_suffix(workflow, "}")?;
+        let workflow = workflow
+            .split(',')
+            .map(|rule| parse_rule(ctx, rule))
+            .collect::<Result<_, _>>()?;
+        workflows.insert(code.to_string(), workflow);
+    }
+    Ok(workflows)
+}
+
+fn parse_parts<'a>(ctx: &Context<'a>, input: &'a str) -> Result<Vec<Part>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let ratings = ctx.strip_suffix(ctx.strip_prefix(line, "{")?, "}")?;
+            let mut iter = ratings.splitn(4, ',');
+            let mut rating = |prefix| {
+                let expected = format!("'{prefix}'");
+                let rating = ctx.next(&mut iter, ratings, &expected)?;
+                ctx.number::<i32>(ctx.strip_prefix(rating, prefix)?)
+            };
+            Ok(Part {
+                x: rating("x=")?,
+                m: rating("m=")?,
+                a: rating("a=")?,
+                s: rating("s=")?,
+            })
+        })
+        .collect()
+}
+
+fn parse_input(input: &str) -> Result<(Workflows, Vec<Part>), ParseError> {
+    let ctx = Context::new(input);
+    let (workflows, parts) = ctx.split_once(input, "\n\n")?;
+    Ok((parse_workflows(&ctx, workflows)?, parse_parts(&ctx, parts)?))
+}
+
+fn accepted_ratings(workflows: &Workflows, parts: &[Part]) -> i32 {
+    let mut result = 0;
+    let source: &str = "in";
+    for part in parts {
+        let destination = find_destination(workflows, part, source);
+        // println!("{part:?} {source} -> {destination}");
+        if destination == "A" {
+            result += part.x + part.m + part.a + part.s;
+        }
+    }
+    result
+}
+
+/// # Errors
+///
+/// Returns an error if a workflow or a part rating is malformed.
+pub fn day19a(input: &str) -> Result<i32, ParseError> {
+    let (workflows, parts) = parse_input(input)?;
+    Ok(accepted_ratings(&workflows, &parts))
+}
+
+/// # Errors
+///
+/// Returns an error if a workflow or a part rating is malformed.
+pub fn day19b(input: &str) -> Result<i64, ParseError> {
+    let (workflows, _) = parse_input(input)?;
+    Ok(accepted_combinations(&workflows))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+
+    type Parsed<'a> = (Workflows, Vec<Part>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a((workflows, parts): &Self::Parsed<'_>) -> Answer {
+        accepted_ratings(workflows, parts).into()
+    }
+
+    fn part_b((workflows, _): &Self::Parsed<'_>) -> Answer {
+        accepted_combinations(workflows).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn input() -> &'static str {
+        "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}"
+    }
+
+    #[test]
+    fn test_19a() {
+        let input = input();
+        assert_eq!(day19a(input), Ok(19114));
+    }
+
+    #[test]
+    fn test_19_invalid_rating() {
+        let input = "in{A}\n\n{x=1,m=2,q=3,s=4}";
+        let error = day19a(input).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (3, 10, "q=")
+        );
+        assert_eq!(error.expected, "'a='");
+    }
+
+    #[test]
+    fn test_19b() {
+        let input = input();
+        assert_eq!(day19b(input), Ok(167_409_079_868_000));
+    }
+
+    #[test]
+    fn test_19_accepted_ranges() {
+        let input = "in{x<2001:lo,m>10:A,R}\nlo{a>3000:R,A}\n\n{x=1,m=2,a=3,s=4}";
+        let (workflows, _) = parse_input(input).unwrap();
+        let ranges = accepted_ranges(&workflows);
+        assert_eq!(
+            ranges,
+            vec![
+                PartRange {
+                    x: 1..=2000,
+                    a: 1..=3000,
+                    ..PartRange::default()
+                },
+                PartRange {
+                    x: 2001..=4000,
+                    m: 11..=4000,
+                    ..PartRange::default()
+                },
+            ]
+        );
+        assert_eq!(ranges[1].combinations(), 2000 * 3990 * 4000 * 4000);
+    }
+}
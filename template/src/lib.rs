@@ -0,0 +1,64 @@
+use common::parse::{Context, ParseError};
+use common::solution::{Answer, Solution};
+
+fn parse_input(input: &str) -> Result<Vec<&str>, ParseError> {
+    let _ctx = Context::new(input);
+    Ok(input.lines().collect())
+}
+
+fn part_a(_lines: &[&str]) -> i64 {
+    0
+}
+
+fn part_b(_lines: &[&str]) -> i64 {
+    0
+}
+
+pub fn dayxa(input: &str) -> Result<i64, ParseError> {
+    Ok(part_a(&parse_input(input)?))
+}
+
+pub fn dayxb(input: &str) -> Result<i64, ParseError> {
+    Ok(part_b(&parse_input(input)?))
+}
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    const DAY: u32 = 0;
+
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a(lines: &Self::Parsed<'_>) -> Answer {
+        part_a(lines).into()
+    }
+
+    fn part_b(lines: &Self::Parsed<'_>) -> Answer {
+        part_b(lines).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn input() -> &'static str {
+        ""
+    }
+
+    #[test]
+    fn test_xa() {
+        let input = input();
+        assert_eq!(dayxa(input), Ok(0));
+    }
+
+    #[test]
+    fn test_xb() {
+        let input = input();
+        assert_eq!(dayxb(input), Ok(0));
+    }
+}